@@ -0,0 +1,38 @@
+use enum_other::other;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum ExtensionType {
+    ServerName = 0,
+    SupportedGroups = 10,
+    #[other(alias = 0xffce)]
+    EncryptedServerName = 0xfe0d,
+    #[other(alias = 0x3374, alias = 0x3375)]
+    NextProtocolNegotiation = 0x3376,
+}
+
+fn main() {
+    assert_eq!(
+        ExtensionType::from(0xffce),
+        ExtensionType::EncryptedServerName
+    );
+    assert_eq!(
+        ExtensionType::from(0xfe0d),
+        ExtensionType::EncryptedServerName
+    );
+    assert_eq!(u16::from(ExtensionType::EncryptedServerName), 0xfe0d);
+
+    assert_eq!(
+        ExtensionType::from(0x3374),
+        ExtensionType::NextProtocolNegotiation
+    );
+    assert_eq!(u16::from(ExtensionType::NextProtocolNegotiation), 0x3376);
+
+    assert_eq!(ExtensionType::from(10), ExtensionType::SupportedGroups);
+    assert_eq!(ExtensionType::from(11), ExtensionType::Other(11));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,48 @@
+use enum_other::other;
+
+type Rgb = (u8, u8, u8);
+
+#[other(Rgb, arity = 3)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Color {
+    Black = (0, 0, 0),
+    White = (255, 255, 255),
+}
+
+#[other(Rgb, Custom { red, green, blue }, arity = 3)]
+#[derive(Debug, PartialEq, Eq)]
+enum Named {
+    Red = (255, 0, 0),
+}
+
+// A fallback variant declared in the enum gives the element types itself.
+#[other(Rgb, arity = 3)]
+#[derive(Debug, PartialEq, Eq)]
+enum Declared {
+    Teal = (0, 128, 128),
+    Other(u8, u8, u8),
+}
+
+fn main() {
+    assert_eq!(Color::from((0, 0, 0)), Color::Black);
+    assert_eq!(Color::from((1, 2, 3)), Color::Other(1, 2, 3));
+    assert_eq!(Rgb::from(Color::Other(4, 5, 6)), (4, 5, 6));
+    assert_eq!(Rgb::from(Color::White), (255, 255, 255));
+
+    assert_eq!(
+        Named::from((1, 2, 3)),
+        Named::Custom {
+            red: 1,
+            green: 2,
+            blue: 3
+        },
+    );
+
+    assert_eq!(Declared::from((0, 128, 128)), Declared::Teal);
+    assert_eq!(Declared::from((9, 9, 9)), Declared::Other(9, 9, 9));
+}
+
+#[test]
+fn run() {
+    main()
+}
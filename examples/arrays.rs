@@ -0,0 +1,51 @@
+use enum_other::other;
+
+/// Protocol versions, as their major and minor number.
+#[other([u16; 2])]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Version {
+    V1 = [1, 0],
+    #[other(alias = [1, 1])]
+    V1_2 = [1, 2],
+    V2 = [2, 0],
+}
+
+/// Tiles of a two by two grid, by the rows of their cells.
+#[other([[u8; 2]; 2])]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty = [[0, 0], [0, 0]],
+    Diagonal = [[1, 0], [0, 1]],
+    Full = [[1, 1], [1, 1]],
+}
+
+/// Points on the axes, the other one holding any other point.
+#[other([i32; 3], Point)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Origin = [0, 0, 0],
+    X = [1, 0, 0],
+    Y = [0, 1, 0],
+    Z = [0, 0, 1],
+}
+
+fn main() {
+    assert_eq!(Version::from([1, 0]), Version::V1);
+    assert_eq!(Version::from([1, 1]), Version::V1_2);
+    assert_eq!(Version::from([3, 4]), Version::Other([3, 4]));
+    assert_eq!(<[u16; 2]>::from(Version::V1_2), [1, 2]);
+    assert_eq!(<[u16; 2]>::from(Version::Other([3, 4])), [3, 4]);
+
+    assert_eq!(Tile::from([[1, 0], [0, 1]]), Tile::Diagonal);
+    assert_eq!(Tile::from([[0, 1], [1, 0]]), Tile::Other([[0, 1], [1, 0]]));
+    assert_eq!(<[[u8; 2]; 2]>::from(Tile::Full), [[1, 1], [1, 1]]);
+
+    assert_eq!(Axis::from([0, 1, 0]), Axis::Y);
+    assert_eq!(Axis::from([-1, 0, 0]), Axis::Point([-1, 0, 0]));
+    assert_eq!(<[i32; 3]>::from(Axis::Z), [0, 0, 1]);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,30 @@
+use enum_other::other;
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Encoding {
+    Gzip = "gzip",
+    Identity,
+    Brotli = "br",
+    Zstd,
+    r#Deflate,
+}
+
+fn main() {
+    assert_eq!(String::from(Encoding::Gzip), "gzip");
+    assert_eq!(String::from(Encoding::Identity), "Identity");
+    assert_eq!(String::from(Encoding::Zstd), "Zstd");
+    assert_eq!(String::from(Encoding::Deflate), "Deflate");
+
+    assert_eq!(Encoding::from("br".to_string()), Encoding::Brotli);
+    assert_eq!(Encoding::from("Identity".to_string()), Encoding::Identity);
+    assert_eq!(
+        Encoding::from("identity".to_string()),
+        Encoding::Other("identity".to_string()),
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
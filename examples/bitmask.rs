@@ -0,0 +1,40 @@
+use enum_other::other;
+
+#[other(u16, bitmask)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Permission {
+    None = 0,
+    OtherExecute = 0o1,
+    OtherWrite = 0o2,
+    OtherRead = 0o4,
+    GroupExecute = 0o10,
+    GroupWrite = 0o20,
+    GroupRead = 0o40,
+    OwnerExecute = 0o100,
+    OwnerWrite = 0o200,
+    OwnerRead = 0o400,
+}
+
+fn main() {
+    let read_write = Permission::OwnerRead | Permission::OwnerWrite;
+    assert_eq!(read_write, Permission::Other(0o600));
+    assert!(read_write.contains(Permission::OwnerWrite));
+    assert!(!read_write.contains(Permission::OwnerExecute));
+
+    assert_eq!(read_write & Permission::OwnerRead, Permission::OwnerRead);
+    assert_eq!(
+        read_write.intersection(Permission::GroupRead),
+        Permission::None
+    );
+    assert_eq!(read_write ^ Permission::OwnerWrite, Permission::OwnerRead);
+    assert_eq!(
+        Permission::OwnerRead.union(Permission::GroupRead),
+        Permission::Other(0o440)
+    );
+    assert_eq!(!Permission::Other(!0o400), Permission::OwnerRead);
+}
+
+#[test]
+fn run() {
+    main()
+}
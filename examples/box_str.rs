@@ -0,0 +1,52 @@
+use enum_other::other;
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Scheme {
+    Http = "http",
+    Https = "https",
+}
+
+/// Storing the unknown values boxed as well converts without reallocating.
+#[other(String, store = Box<str>)]
+#[derive(Debug, PartialEq, Eq)]
+enum Encoding {
+    Gzip = "gzip",
+}
+
+#[other(String, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum Level {
+    Debug = "debug",
+}
+
+fn main() {
+    assert_eq!(Scheme::from(Box::<str>::from("https")), Scheme::Https);
+    assert_eq!(
+        Scheme::from(Box::<str>::from("ftp")),
+        Scheme::Other("ftp".to_string())
+    );
+    assert_eq!(Box::<str>::from(Scheme::Http), Box::from("http"));
+    assert_eq!(
+        Box::<str>::from(Scheme::Other("ftp".to_string())),
+        Box::from("ftp")
+    );
+
+    assert_eq!(
+        Encoding::from(Box::<str>::from("br")),
+        Encoding::Other(Box::from("br"))
+    );
+    assert_eq!(Box::<str>::from(Encoding::Gzip), Box::from("gzip"));
+
+    assert_eq!(Level::try_from(Box::<str>::from("debug")), Ok(Level::Debug));
+    assert_eq!(
+        Level::try_from(Box::<str>::from("trace")),
+        Err("trace".to_string())
+    );
+    assert_eq!(Box::<str>::from(Level::Debug), Box::from("debug"));
+}
+
+#[test]
+fn run() {
+    main()
+}
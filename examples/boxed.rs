@@ -0,0 +1,52 @@
+use std::mem;
+
+use enum_other::other;
+
+type Fingerprint = (u64, u64, u64, u64);
+
+/// Unknown fingerprints are rare, so they are kept out of line.
+#[other((u64, u64, u64, u64), boxed, display, from_ref)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum KnownKey {
+    Root = (1, 2, 3, 4),
+    Staging = (5, 6, 7, 8),
+}
+
+#[other((u64, u64, u64, u64), display)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InlineKey {
+    Root = (1, 2, 3, 4),
+}
+
+#[other(Fingerprint, arity = 4, boxed)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AliasedKey {
+    Root = (1, 2, 3, 4),
+}
+
+fn main() {
+    assert!(mem::size_of::<KnownKey>() < mem::size_of::<InlineKey>());
+    assert_eq!(mem::size_of::<KnownKey>(), 2 * mem::size_of::<usize>());
+    assert_eq!(
+        mem::size_of::<AliasedKey>(),
+        mem::size_of::<Box<Fingerprint>>()
+    );
+
+    let key = KnownKey::from((9, 9, 9, 9));
+    assert_eq!(key, KnownKey::Other(Box::new((9, 9, 9, 9))));
+    assert_eq!(key.to_string(), InlineKey::from((9, 9, 9, 9)).to_string());
+    assert_eq!(Fingerprint::from(&key), (9, 9, 9, 9));
+    assert_eq!(Fingerprint::from(key), (9, 9, 9, 9));
+    assert_eq!(KnownKey::from((5, 6, 7, 8)), KnownKey::Staging);
+
+    assert_eq!(
+        AliasedKey::from((0, 0, 0, 0)),
+        AliasedKey::Other(Box::new((0, 0, 0, 0)))
+    );
+    assert_eq!(Fingerprint::from(AliasedKey::Root), (1, 2, 3, 4));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,34 @@
+use enum_other::other;
+
+/// Tokens of a line protocol, named by the byte they start with.
+#[other(u8, repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prefix {
+    SimpleString = b'+',
+    Error = b'-',
+    Integer = b':',
+    // Inferred discriminants continue from the byte before.
+    Digit0 = b'0',
+    Digit1,
+    Digit2,
+    Null = 0,
+    #[other(alias = b'%')]
+    Map = b'%' + 1,
+}
+
+fn main() {
+    assert_eq!(Prefix::from(b'+'), Prefix::SimpleString);
+    assert_eq!(Prefix::from(43), Prefix::SimpleString);
+    assert_eq!(Prefix::from(b'1'), Prefix::Digit1);
+    assert_eq!(u8::from(Prefix::Digit2), b'2');
+    assert_eq!(Prefix::from(b'%'), Prefix::Map);
+    assert_eq!(u8::from(Prefix::Map), b'&');
+    assert_eq!(Prefix::from(0), Prefix::Null);
+    assert_eq!(Prefix::from(b'*'), Prefix::Other(b'*'));
+    assert_eq!(u8::from(Prefix::Error), b'-');
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use enum_other::other;
+
+#[other(Vec<u8>)]
+#[derive(Debug, PartialEq, Eq)]
+enum TlvType {
+    Padding = b"\x00",
+    Hostname = b"\x01\x02",
+    #[other(alias = b"\x01\x03")]
+    Port = b"\x01\x04",
+}
+
+/// Commands of a text protocol, which need not be UTF-8.
+#[other(Vec<u8>, on_unknown = count_unknown)]
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Ping = b"PING",
+    Quit = b"QUIT",
+}
+
+static UNKNOWN: AtomicUsize = AtomicUsize::new(0);
+
+fn count_unknown(_: &Vec<u8>) {
+    UNKNOWN.fetch_add(1, Ordering::Relaxed);
+}
+
+#[other(Box<[u8]>)]
+#[derive(Debug, PartialEq, Eq)]
+enum Magic {
+    Png = b"\x89PNG",
+    Gif = b"GIF8",
+}
+
+fn main() {
+    assert_eq!(TlvType::from(vec![1, 2]), TlvType::Hostname);
+    assert_eq!(TlvType::from(vec![1, 3]), TlvType::Port);
+    assert_eq!(TlvType::from(vec![9, 9, 9]), TlvType::Other(vec![9, 9, 9]));
+    assert_eq!(Vec::<u8>::from(TlvType::Port), [1, 4]);
+    assert_eq!(Vec::<u8>::from(TlvType::Other(vec![7])), [7]);
+
+    assert_eq!(Magic::from(Box::from(&b"GIF8"[..])), Magic::Gif);
+    assert_eq!(&*Box::<[u8]>::from(Magic::Png), b"\x89PNG");
+    assert_eq!(Magic::from(&b"GIF8"[..]), Magic::Gif);
+
+    // Slices are only copied into the "other" variant.
+    let line = b"PING\r\nAUTH\r\n";
+    let commands = line
+        .split(|&byte| byte == b'\n')
+        .filter_map(|line| line.strip_suffix(b"\r"))
+        .map(Command::from)
+        .collect::<Vec<_>>();
+    assert_eq!(commands, [Command::Ping, Command::Other(b"AUTH".to_vec())]);
+    assert_eq!(UNKNOWN.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn run() {
+    main()
+}
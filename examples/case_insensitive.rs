@@ -0,0 +1,39 @@
+use enum_other::other;
+
+const TRANSFER_ENCODING: &str = "Transfer-Encoding";
+
+#[other(String, case_insensitive)]
+#[derive(Debug, PartialEq, Eq)]
+enum HeaderName {
+    Host = "Host",
+    #[other(alias = "Content-Len")]
+    ContentLength = "Content-Length",
+    TransferEncoding = TRANSFER_ENCODING,
+    Etag,
+}
+
+fn main() {
+    assert_eq!(HeaderName::from("host".to_string()), HeaderName::Host);
+    assert_eq!(HeaderName::from("HOST".to_string()), HeaderName::Host);
+    assert_eq!(
+        HeaderName::from("content-len".to_string()),
+        HeaderName::ContentLength,
+    );
+    assert_eq!(
+        HeaderName::from("transfer-encoding".to_string()),
+        HeaderName::TransferEncoding,
+    );
+    assert_eq!(HeaderName::from("ETAG".to_string()), HeaderName::Etag);
+
+    assert_eq!(String::from(HeaderName::ContentLength), "Content-Length");
+    assert_eq!(String::from(HeaderName::Etag), "Etag");
+    assert_eq!(
+        HeaderName::from("X-Custom".to_string()),
+        HeaderName::Other("X-Custom".to_string()),
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
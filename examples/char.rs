@@ -0,0 +1,31 @@
+use enum_other::other;
+
+/// Command line flags, named by their letter.
+#[other(char, display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flag {
+    Verbose = 'v',
+    #[other(alias = 'h')]
+    Help = '?',
+    #[other(alias = 'R')]
+    Recursive = 'r',
+}
+
+fn main() {
+    assert_eq!(Flag::from('v'), Flag::Verbose);
+    assert_eq!(Flag::from('h'), Flag::Help);
+    assert_eq!(Flag::from('R'), Flag::Recursive);
+    assert_eq!(Flag::from('x'), Flag::Other('x'));
+
+    assert_eq!(char::from(Flag::Help), '?');
+    assert_eq!(char::from(Flag::Other('x')), 'x');
+    let flags = "vhq".chars().map(Flag::from).collect::<Vec<_>>();
+    assert_eq!(flags, [Flag::Verbose, Flag::Help, Flag::Other('q')]);
+    assert_eq!(Flag::Verbose.to_string(), "Verbose");
+    assert_eq!(Flag::Other('x').to_string(), "x");
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,44 @@
+use enum_other::other;
+
+mod record {
+    pub const A: u16 = 1;
+    pub const NS: u16 = 2;
+    pub const MD: u16 = 3;
+    pub const MX: u16 = 15;
+}
+
+const ROOT: &str = "/";
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = record::A,
+    Ns = record::NS,
+    #[other(alias = record::MD)]
+    Mx = record::MX,
+    Txt = 16,
+}
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Path {
+    Root = ROOT,
+    Index = "/index.html",
+}
+
+fn main() {
+    assert_eq!(RecordType::from(2), RecordType::Ns);
+    assert_eq!(RecordType::from(3), RecordType::Mx);
+    assert_eq!(RecordType::from(16), RecordType::Txt);
+    assert_eq!(u16::from(RecordType::Mx), 15);
+    assert_eq!(RecordType::from(4), RecordType::Other(4));
+
+    assert_eq!(Path::from("/".to_string()), Path::Root);
+    assert_eq!(String::from(Path::Root), "/");
+    assert_eq!(Path::from("/a".to_string()), Path::Other("/a".to_string()));
+}
+
+#[test]
+fn run() {
+    main()
+}
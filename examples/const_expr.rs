@@ -0,0 +1,36 @@
+use enum_other::other;
+
+const BASE: u16 = 0x100;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum Register {
+    Status = 1 << 4,
+    Control, // = 17
+    Mode = 0x20 | 0x01,
+    Interrupt, // = 34
+    Extended = BASE + 1,
+    Last = u16::MAX - 1,
+    First = u16::MIN,
+}
+
+fn main() {
+    assert_eq!(Register::from(16), Register::Status);
+    assert_eq!(Register::from(17), Register::Control);
+    assert_eq!(Register::from(0x21), Register::Mode);
+    assert_eq!(Register::from(34), Register::Interrupt);
+    assert_eq!(Register::from(0x101), Register::Extended);
+    assert_eq!(Register::from(0xfffe), Register::Last);
+    assert_eq!(Register::from(0), Register::First);
+    assert_eq!(Register::from(0xffff), Register::Other(0xffff));
+
+    assert_eq!(u16::from(Register::Mode), 0x21);
+    assert_eq!(u16::from(Register::Extended), 0x101);
+    assert_eq!(u16::from(Register::Last), 0xfffe);
+    assert_eq!(u16::from(Register::First), 0);
+}
+
+#[test]
+fn run() {
+    main()
+}
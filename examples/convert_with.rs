@@ -0,0 +1,61 @@
+use enum_other::other;
+
+mod v1 {
+    #[enum_other::other(u16)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum RecordType {
+        A = 1,
+        Ns = 2,
+        Md = 3,
+    }
+}
+
+#[other(u16, convert_with = v1::RecordType)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    Aaaa = 28,
+}
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum OldMethod {
+    Get = "GET",
+    Post = "POST",
+}
+
+#[other(String, convert_with = OldMethod)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    Patch = "PATCH",
+}
+
+fn main() {
+    assert_eq!(RecordType::from(v1::RecordType::Ns), RecordType::Ns);
+    assert_eq!(RecordType::from(v1::RecordType::Md), RecordType::Other(3));
+    assert_eq!(
+        v1::RecordType::from(RecordType::Aaaa),
+        v1::RecordType::Other(28)
+    );
+    assert_eq!(
+        v1::RecordType::from(RecordType::Other(3)),
+        v1::RecordType::Md
+    );
+
+    assert_eq!(Method::from(OldMethod::Get), Method::Get);
+    assert_eq!(
+        Method::from(OldMethod::Post),
+        Method::Other("POST".to_owned())
+    );
+    assert_eq!(
+        OldMethod::from(Method::Patch),
+        OldMethod::Other("PATCH".to_owned())
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
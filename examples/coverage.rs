@@ -0,0 +1,63 @@
+use std::panic;
+
+use enum_other::{assert_coverage, other};
+
+#[other(u8)]
+#[derive(Debug)]
+enum Opcode {
+    Query,
+    IQuery,
+    Status,
+    Notify = 4,
+    Update,
+}
+
+#[other(i16)]
+#[derive(Debug)]
+enum Offset {
+    Back = -1,
+    Stay = 0,
+    Forward = 1,
+    Reserved = 0x7f00..=0x7fff,
+}
+
+#[other(u32, bitmask)]
+#[derive(Debug)]
+enum Permission {
+    Read = 1 << 0,
+    Write = 1 << 1,
+    Execute = 1 << 2,
+    Admin = 1 << 31,
+}
+
+#[other(u64)]
+#[derive(Debug)]
+enum Magic {
+    Elf = 0x7f45_4c46,
+    Max = u64::MAX,
+}
+
+#[other(u8)]
+#[derive(Debug)]
+enum Ack {
+    Yes = 1,
+    #[other(alias = 6)]
+    No = 0x15,
+}
+
+fn main() {
+    assert_coverage!(Opcode, u8);
+    assert_coverage!(Offset, i16);
+    assert_coverage!(Permission, u32);
+    assert_coverage!(Magic, u64);
+
+    // Aliases are not encoded, so they do not round-trip.
+    panic::set_hook(Box::new(|_| {}));
+    assert!(panic::catch_unwind(|| assert_coverage!(Ack, u8)).is_err());
+    let _ = panic::take_hook();
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use enum_other::other;
+
+/// HTTP methods, encoded without allocating for the known ones.
+#[other(Cow<'static, str>, rename_all = "UPPERCASE")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Method {
+    Get,
+    #[other(alias = "post")]
+    Post,
+    #[other(rename = "DEL")]
+    Delete,
+    Patch = "PATCH",
+}
+
+fn main() {
+    assert_eq!(Method::from(Cow::Borrowed("GET")), Method::Get);
+    assert_eq!(Method::from(Cow::Owned("post".to_owned())), Method::Post);
+    assert_eq!(Method::from(Cow::Borrowed("DEL")), Method::Delete);
+    assert_eq!(Method::DISCRIMINANTS, ["GET", "POST", "DEL", "PATCH"]);
+
+    assert!(matches!(Cow::from(Method::Get), Cow::Borrowed("GET")));
+    assert!(matches!(Cow::from(Method::Patch), Cow::Borrowed("PATCH")));
+
+    // Unknown values keep the `Cow` they came in.
+    let put = Method::from(Cow::Borrowed("PUT"));
+    assert_eq!(put, Method::Other(Cow::Borrowed("PUT")));
+    assert!(matches!(Cow::from(put), Cow::Borrowed("PUT")));
+    let trace = Method::from(Cow::Owned("TRACE".to_owned()));
+    assert!(matches!(Cow::from(trace), Cow::Owned(value) if value == "TRACE"));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,42 @@
+//! Checked out with CRLF line endings on every platform, see `.gitattributes`.
+
+use enum_other::other;
+
+/// Request lines of a text protocol, ended by CRLF.
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    /// Escaped line endings are kept as written.
+    Quit = "QUIT\r\n",
+    // Line breaks in the source are read as `\n`, whatever the line endings of
+    // the file.
+    Help = "HELP
+",
+    // Continued lines skip the line break and the indentation.
+    Noop = "NO\
+            OP\r\n",
+    #[other(alias = "list\r\n")]
+    List = "LIST\r\n",
+}
+
+fn main() {
+    assert_eq!(Command::from("QUIT\r\n"), Command::Quit);
+    assert_eq!(Command::from("HELP\n"), Command::Help);
+    assert_eq!(Command::from("NOOP\r\n"), Command::Noop);
+    assert_eq!(Command::from("list\r\n"), Command::List);
+    assert_eq!(Command::from("QUIT\n"), Command::Other("QUIT\n".to_owned()));
+    assert_eq!(
+        Command::from("HELP\r\n"),
+        Command::Other("HELP\r\n".to_owned())
+    );
+
+    assert_eq!(String::from(Command::Quit), "QUIT\r\n");
+    assert_eq!(String::from(Command::Help), "HELP\n");
+    assert_eq!(String::from(Command::Noop), "NOOP\r\n");
+    assert_eq!(String::from(Command::List), "LIST\r\n");
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,52 @@
+use enum_other::other;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum RecordType {
+    A = 1,
+    /// A record type this crate does not know about.
+    Other(u16),
+    Ns = 2,
+}
+
+#[other((u8, u8), Unknown, display)]
+#[derive(Debug, PartialEq, Eq)]
+enum Version {
+    #[deprecated = "use the known versions"]
+    Unknown {
+        major: u8,
+        minor: u8,
+    },
+    First = (1, 0),
+}
+
+/// The fallback variant can name the data type through any path.
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    Other(std::string::String),
+}
+
+fn main() {
+    assert_eq!(RecordType::from(2), RecordType::Ns);
+    assert_eq!(RecordType::from(9), RecordType::Other(9));
+    assert_eq!(u16::from(RecordType::Other(5)), 5);
+    assert!(RecordType::A < RecordType::Other(0));
+    assert!(RecordType::Other(0) < RecordType::Ns);
+
+    assert_eq!(Version::from((1, 0)), Version::First);
+    assert_eq!(Version::from((3, 1)).into_other(), Some((3, 1)));
+    assert_eq!(Version::from((3, 1)).to_string(), "(3, 1)");
+
+    assert_eq!(Method::from("GET".to_string()), Method::Get);
+    assert_eq!(
+        Method::from("PUT".to_string()),
+        Method::Other("PUT".to_string())
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
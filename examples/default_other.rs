@@ -0,0 +1,36 @@
+use enum_other::other;
+
+#[other((u8, u8, u8), default_other)]
+#[derive(Debug, PartialEq, Eq)]
+enum Color {
+    Red = (255, 0, 0),
+    Black = (0, 0, 0),
+}
+
+#[other(String, default_other)]
+#[derive(Debug, PartialEq, Eq)]
+enum Scheme {
+    Http = "http",
+}
+
+#[other(u16, named_other = [code], default_other)]
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Ok = 200,
+}
+
+fn main() {
+    // The default value may be known, but is still held by the "other"
+    // variant.
+    assert_eq!(Color::default(), Color::Other(0, 0, 0));
+    assert_ne!(Color::default(), Color::Black);
+    assert_eq!(<(u8, u8, u8)>::from(Color::default()), (0, 0, 0));
+
+    assert_eq!(Scheme::default(), Scheme::Other(String::new()));
+    assert_eq!(Status::default(), Status::Other { code: 0 });
+}
+
+#[test]
+fn run() {
+    main()
+}
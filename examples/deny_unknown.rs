@@ -0,0 +1,42 @@
+use std::panic;
+
+use enum_other::other;
+
+/// Opcodes coming from our own assembler, so unknown ones are a bug.
+#[other(u8, deny_unknown)]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    Nop = 0x00,
+    Halt = 0x76,
+}
+
+#[other(String, deny_unknown)]
+#[derive(Debug, PartialEq, Eq)]
+enum Section {
+    Text = "text",
+    Data = "data",
+}
+
+fn main() {
+    assert_eq!(Opcode::from(0x76), Opcode::Halt);
+    assert_eq!(Section::from("data"), Section::Data);
+    assert_eq!(Opcode::try_from_known(0x01), None);
+
+    // Keep the expected panics out of the output.
+    panic::set_hook(Box::new(|_| {}));
+    let opcode = panic::catch_unwind(|| Opcode::from(0x01));
+    let section = panic::catch_unwind(|| Section::from("bss"));
+    if cfg!(debug_assertions) {
+        assert!(opcode.is_err());
+        assert!(section.is_err());
+    } else {
+        assert_eq!(opcode.unwrap(), Opcode::Other(0x01));
+        assert_eq!(section.unwrap(), Section::Other("bss".to_owned()));
+    }
+    let _ = panic::take_hook();
+}
+
+#[test]
+fn run() {
+    main()
+}
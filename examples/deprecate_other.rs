@@ -0,0 +1,41 @@
+use enum_other::other;
+
+#[other(u16, deprecate_other, display, from_ref)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    Private = 0xff00..,
+}
+
+#[other(String, Unknown, hide_other, deprecate_other)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    #[other(prefix = "X-")]
+    Extension,
+}
+
+fn main() {
+    assert_eq!(RecordType::from(2), RecordType::Ns);
+    assert_eq!(RecordType::from(7).into_other(), Some(7));
+    assert_eq!(u16::from(&RecordType::from(7)), 7);
+    assert_eq!(RecordType::from(7).to_string(), "7");
+    assert_eq!(RecordType::try_from_known(1), Some(RecordType::A));
+
+    assert_eq!(Method::from("GET"), Method::Get);
+    assert_eq!(
+        Method::from("PATCH").into_other(),
+        Some("PATCH".to_string())
+    );
+    assert_eq!(String::from(Method::from("PATCH")), "PATCH");
+
+    #[allow(deprecated)]
+    let other = RecordType::Other(9);
+    assert_eq!(u16::from(other), 9);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,52 @@
+use enum_other::other;
+
+#[other(u8, display)]
+#[other_mapping(name: &'static str)]
+#[derive(Debug, PartialEq, Eq)]
+enum Compression {
+    #[other(name = "none")]
+    None = 0,
+    #[deprecated = "use `Zstd` instead"]
+    #[other(alias = 3, name = "lz4")]
+    Lz4 = 1,
+    #[other(name = "zstd")]
+    Zstd = 2,
+    #[deprecated]
+    Legacy = 100..200,
+}
+
+#[other(String, strict, rename_all = "kebab-case")]
+#[derive(Debug, PartialEq, Eq)]
+enum Channel {
+    Stable,
+    #[deprecated]
+    #[other(prefix = "nightly-")]
+    Nightly,
+}
+
+fn main() {
+    assert_eq!(Compression::from(2), Compression::Zstd);
+    assert_eq!(u8::from(Compression::Zstd), 2);
+    assert_eq!(Compression::from(7), Compression::Other(7));
+    assert_eq!(Compression::from(3).to_string(), "Lz4");
+    assert_eq!(Compression::from_name("Zstd"), Some(Compression::Zstd));
+    assert_eq!(Compression::try_from("zstd"), Ok(Compression::Zstd));
+
+    #[allow(deprecated)]
+    {
+        assert_eq!(Compression::from(1), Compression::Lz4);
+        assert_eq!(Compression::from(150), Compression::Legacy(150));
+        assert_eq!(Compression::from_name("Lz4"), Some(Compression::Lz4));
+        assert_eq!(<&str>::try_from(Compression::Lz4), Ok("lz4"));
+        assert_eq!(
+            Channel::try_from("nightly-2024".to_owned()),
+            Ok(Channel::Nightly("nightly-2024".to_owned()))
+        );
+    }
+    assert_eq!(Channel::try_from("stable".to_owned()), Ok(Channel::Stable));
+}
+
+#[test]
+fn run() {
+    main()
+}
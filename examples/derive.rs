@@ -0,0 +1,50 @@
+use enum_other::EnumOther;
+
+#[derive(Debug, PartialEq, Eq, EnumOther)]
+#[other(type = u16, ident = Unknown, display)]
+#[repr(u16)]
+enum RecordType {
+    A = 1,
+    #[other(display = "Name server")]
+    Ns = 2,
+    Cname = 5,
+    /// A record type this crate does not know about.
+    Unknown(u16),
+}
+
+#[derive(Debug, PartialEq, Eq, EnumOther)]
+#[other(type = String, rename_all = "lowercase")]
+enum Method {
+    Get,
+    #[other(alias = "PUT")]
+    Post,
+    Other(String),
+}
+
+#[derive(Debug, PartialEq, Eq, EnumOther)]
+#[other(type = u8, strict)]
+enum Level {
+    Low = 1,
+    High = 2,
+}
+
+fn main() {
+    assert_eq!(RecordType::from(5), RecordType::Cname);
+    assert_eq!(RecordType::from(7), RecordType::Unknown(7));
+    assert_eq!(u16::from(RecordType::Ns), 2);
+    assert_eq!(RecordType::Ns.to_string(), "Name server");
+
+    assert_eq!(Method::from("get"), Method::Get);
+    assert_eq!(Method::from("PUT"), Method::Post);
+    assert_eq!(String::from(Method::Post), "post");
+    assert_eq!(Method::from("head"), Method::Other("head".to_owned()));
+
+    assert_eq!(Level::try_from(2), Ok(Level::High));
+    assert_eq!(Level::try_from(3), Err(3));
+    assert_eq!(u8::from(Level::Low), 1);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,51 @@
+use enum_other::other;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    Cname = 5,
+    Private = 65280..=65534,
+}
+
+pub const TXT: u16 = 16;
+
+#[other(u16, ident = Unknown)]
+enum Extended {
+    Txt = TXT,
+    Aaaa = 28,
+}
+
+#[other(String, rename_all = "kebab-case")]
+enum Encoding {
+    Gzip,
+    #[other(alias = "x-compress")]
+    Compress,
+    #[other(rename = "br")]
+    Brotli,
+}
+
+#[other(Vec<u8>)]
+enum Magic {
+    Png = b"\x89PNG",
+    Gif = b"GIF8",
+}
+
+fn main() {
+    assert_eq!(RecordType::DISCRIMINANTS, [1, 2, 5]);
+    assert!(RecordType::DISCRIMINANTS.contains(&5));
+    assert!(!RecordType::DISCRIMINANTS.contains(&65280));
+    assert_eq!(Extended::DISCRIMINANTS, [16, 28]);
+    assert_eq!(Encoding::DISCRIMINANTS, ["gzip", "compress", "br"]);
+    assert_eq!(Magic::DISCRIMINANTS, [&b"\x89PNG"[..], b"GIF8"]);
+
+    // The length is known at compile time.
+    const COUNT: usize = RecordType::DISCRIMINANTS.len();
+    assert_eq!(COUNT, 3);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,46 @@
+use enum_other::other;
+
+#[other(u16, display)]
+#[derive(Debug, PartialEq, Eq)]
+enum DnsRecordType {
+    #[other(display = "IPv4 address record")]
+    A = 1,
+    #[other(display = "Name server record")]
+    Ns = 2,
+    #[other(display = "Mail exchange record")]
+    Mx = 15,
+    Txt = 16,
+    #[other(display = "Private use record")]
+    Private = 65280..=65534,
+}
+
+#[other((u8, u8), display)]
+#[derive(Debug, PartialEq, Eq)]
+enum Version {
+    #[other(display = "1.0")]
+    V1 = (1, 0),
+    #[other(display = "1.1")]
+    V1_1 = (1, 1),
+}
+
+fn main() {
+    assert_eq!(DnsRecordType::Mx.label(), Some("Mail exchange record"));
+    assert_eq!(DnsRecordType::Txt.label(), Some("Txt"));
+    assert_eq!(
+        DnsRecordType::from(65300).label(),
+        Some("Private use record"),
+    );
+    assert_eq!(DnsRecordType::from(99).label(), None);
+
+    assert_eq!(DnsRecordType::A.to_string(), "IPv4 address record");
+    assert_eq!(DnsRecordType::from(99).to_string(), "99");
+
+    assert_eq!(Version::from((1, 1)).to_string(), "1.1");
+    assert_eq!(Version::from((2, 0)).to_string(), "(2, 0)");
+    assert_eq!(Version::V1.label(), Some("1.0"));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,38 @@
+//! The aliases only show up in the documentation, where searching for `28`
+//! or `0x1c` finds `RecordType::Aaaa`. Rustdoc rejects invalid aliases when
+//! compiling, which this checks.
+
+use enum_other::other;
+
+#[other(u16, doc_aliases)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecordType {
+    A = 1,
+    Ns,
+    /// An alias already written is not repeated.
+    #[doc(alias = "28")]
+    Aaaa = 28,
+    #[doc(alias("0x21", "service"))]
+    Srv = 33,
+}
+
+#[other(String, doc_aliases)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Scheme {
+    Http = "http",
+    /// Aliases equal to the name of the variant are left out.
+    Ftp = "Ftp",
+}
+
+fn main() {
+    assert_eq!(RecordType::from(2), RecordType::Ns);
+    assert_eq!(RecordType::from(28), RecordType::Aaaa);
+    assert_eq!(u16::from(RecordType::Srv), 33);
+    assert_eq!(Scheme::from("http"), Scheme::Http);
+    assert_eq!(String::from(Scheme::Ftp), "Ftp");
+}
+
+#[test]
+fn run() {
+    main()
+}
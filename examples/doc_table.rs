@@ -0,0 +1,32 @@
+use enum_other::other;
+
+/// Status codes of the API, with the mapping documented on the conversions.
+#[other(u16, doc_table)]
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Ok = 200,
+    #[other(alias = 302)]
+    Moved = 301,
+    ServerError = 500..600,
+}
+
+#[other(String, strict, doc_table)]
+#[derive(Debug, PartialEq, Eq)]
+enum Separator {
+    Pipe = "|",
+    Comma = ",",
+}
+
+fn main() {
+    assert_eq!(Status::from(302), Status::Moved);
+    assert_eq!(Status::from(503), Status::ServerError(503));
+    assert_eq!(u16::from(Status::Other(404)), 404);
+
+    assert_eq!(Separator::try_from("|".to_owned()), Ok(Separator::Pipe));
+    assert_eq!(String::from(Separator::Comma), ",");
+}
+
+#[test]
+fn run() {
+    main()
+}
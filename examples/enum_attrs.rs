@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+
+use enum_other::other;
+
+/// Attributes put above the macro are kept as well.
+#[derive(Clone, Copy)]
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(non_camel_case_types)]
+#[cfg_attr(all(), derive(Default))]
+#[cfg_attr(any(), derive(NotATrait))]
+#[must_use]
+enum OpCode {
+    #[cfg_attr(all(), default)]
+    op_nop = 0x00,
+    op_halt = 0x76,
+}
+
+#[other(String)]
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+#[cfg_attr(test, derive(Eq, Hash))]
+enum Protocol {
+    HTTP = "http",
+    FTP = "ftp",
+}
+
+fn main() {
+    assert_eq!(OpCode::default(), OpCode::op_nop);
+    assert!(OpCode::from(0x76) > OpCode::op_nop);
+    let opcode = OpCode::from(0x01);
+    assert_eq!(u8::from(opcode), 0x01);
+    let set = [OpCode::op_halt, OpCode::from(0x76), opcode]
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+
+    let protocol = Protocol::from("gopher");
+    assert_eq!(protocol.clone(), Protocol::Other("gopher".to_owned()));
+    assert_eq!(String::from(Protocol::FTP), "ftp");
+    assert_ne!(Protocol::HTTP, protocol);
+}
+
+#[test]
+fn run() {
+    main();
+
+    // Only derived in test builds.
+    let set = [Protocol::HTTP, Protocol::from("http")]
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+}
@@ -0,0 +1,51 @@
+use std::{error::Error, fmt};
+
+use enum_other::other;
+
+#[other(u16, error)]
+#[derive(Debug, PartialEq, Eq)]
+enum HttpStatusCode {
+    NotFound = 404,
+    Teapot = 418,
+    ServerError = 500..600,
+}
+
+#[other(u16, error, display)]
+#[derive(Debug)]
+enum Rcode {
+    #[other(display = "server failure")]
+    ServFail = 2,
+}
+
+/// An error holding the status as its source, like `thiserror` would.
+#[derive(Debug)]
+struct RequestError(HttpStatusCode);
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("request failed")
+    }
+}
+
+impl Error for RequestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+fn main() {
+    assert_eq!(HttpStatusCode::Teapot.to_string(), "Teapot (418)");
+    assert_eq!(HttpStatusCode::from(503).to_string(), "ServerError (503)");
+    assert_eq!(HttpStatusCode::from(299).to_string(), "299");
+
+    let error = RequestError(HttpStatusCode::from(404));
+    assert_eq!(error.source().unwrap().to_string(), "NotFound (404)");
+
+    let boxed: Box<dyn Error> = Box::new(Rcode::ServFail);
+    assert_eq!(boxed.to_string(), "server failure");
+}
+
+#[test]
+fn run() {
+    main()
+}
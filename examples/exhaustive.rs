@@ -0,0 +1,56 @@
+use enum_other::other;
+
+/// Every byte is a known class, so no fallback variant is needed.
+#[other(u8, exhaustive)]
+#[derive(Debug, PartialEq, Eq)]
+enum ByteClass {
+    Nul = 0,
+    Control = 1..=31,
+    Printable = 32..=126,
+    #[other(alias = 255)]
+    Delete = 127,
+    High = 128..=254,
+}
+
+#[other(i8, exhaustive)]
+#[derive(Debug, PartialEq, Eq)]
+enum Sign {
+    Negative = ..0,
+    Zero = 0,
+    Positive = 1..,
+}
+
+fn describe(class: ByteClass) -> &'static str {
+    // No wildcard arm is needed.
+    match class {
+        ByteClass::Nul => "nul",
+        ByteClass::Control(_) => "control",
+        ByteClass::Printable(_) => "printable",
+        ByteClass::Delete => "delete",
+        ByteClass::High(_) => "high",
+    }
+}
+
+fn main() {
+    assert_eq!(ByteClass::from(b'a'), ByteClass::Printable(b'a'));
+    assert_eq!(ByteClass::from(255), ByteClass::Delete);
+    assert_eq!(u8::from(ByteClass::Delete), 127);
+    assert_eq!(describe(ByteClass::from(0x80)), "high");
+    assert_eq!(
+        (0..=255)
+            .map(ByteClass::from)
+            .map(u8::from)
+            .filter(|&byte| byte == 127)
+            .count(),
+        2
+    );
+
+    assert_eq!(Sign::from(-5), Sign::Negative(-5));
+    assert_eq!(Sign::from(0), Sign::Zero);
+    assert_eq!(i8::from(Sign::Positive(7)), 7);
+}
+
+#[test]
+fn run() {
+    main()
+}
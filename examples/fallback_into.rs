@@ -0,0 +1,59 @@
+use enum_other::other;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum VendorOption {
+    Padding = 0xfde9,
+    Trace = 0xfdea,
+}
+
+#[other(u16, fallback_into = VendorOption)]
+#[derive(Debug, PartialEq, Eq)]
+enum EdnsOption {
+    Nsid = 3,
+    ClientSubnet = 8,
+    Cookie = 10,
+}
+
+#[other(String, fallback_into = Extension, display)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+}
+
+#[other(String, Unknown, display)]
+#[derive(Debug, PartialEq, Eq)]
+enum Extension {
+    Purge = "PURGE",
+}
+
+fn main() {
+    assert_eq!(EdnsOption::from(8), EdnsOption::ClientSubnet);
+    assert_eq!(
+        EdnsOption::from(0xfdea),
+        EdnsOption::Other(VendorOption::Trace)
+    );
+    assert_eq!(
+        EdnsOption::from(99),
+        EdnsOption::Other(VendorOption::Other(99))
+    );
+    assert_eq!(u16::from(EdnsOption::Other(VendorOption::Padding)), 0xfde9);
+    assert_eq!(u16::from(&EdnsOption::Other(VendorOption::Other(99))), 99);
+    assert_eq!(
+        EdnsOption::from(0xfde9).into_other(),
+        Some(VendorOption::Padding)
+    );
+
+    assert_eq!(Method::from("PURGE"), Method::Other(Extension::Purge));
+    assert_eq!(
+        Method::from("LINK".to_string()),
+        Method::Other(Extension::Unknown("LINK".to_string()))
+    );
+    assert_eq!(String::from(Method::Other(Extension::Purge)), "PURGE");
+    assert_eq!(Method::from("PURGE").to_string(), "Purge");
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,31 @@
+use enum_other::other;
+
+#[other(u16, fallbacks(Private = 0xff00..=0xffff, Reserved = 0x8000..=0xfeff, Unassigned = _))]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    Cname = 5,
+}
+
+fn main() {
+    assert_eq!(RecordType::from(5), RecordType::Cname);
+    assert_eq!(RecordType::from(0xff10), RecordType::Private(0xff10));
+    assert_eq!(RecordType::from(0x8001), RecordType::Reserved(0x8001));
+    assert_eq!(RecordType::from(300), RecordType::Unassigned(300));
+
+    assert_eq!(u16::from(RecordType::Private(0xff10)), 0xff10);
+    assert_eq!(u16::from(RecordType::Reserved(0x8001)), 0x8001);
+    assert_eq!(u16::from(RecordType::Unassigned(300)), 300);
+
+    assert_eq!(
+        RecordType::try_from_known(0x8001),
+        Some(RecordType::Reserved(0x8001))
+    );
+    assert_eq!(RecordType::Unassigned(300).into_other(), Some(300));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,34 @@
+use enum_other::other;
+
+/// Sentinel readings of a legacy sensor format.
+#[other(f32, Reading)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sample {
+    Missing = f32::NAN,
+    Saturated = f32::INFINITY,
+    #[other(alias = 0.5)]
+    Half = 0.50,
+    Zero = 0.0,
+}
+
+fn main() {
+    assert_eq!(Sample::from(0.5), Sample::Half);
+    assert_eq!(Sample::from(f32::INFINITY), Sample::Saturated);
+    assert_eq!(Sample::from(0.0), Sample::Zero);
+    assert_eq!(Sample::from(1.25), Sample::Reading(1.25));
+
+    // Matching is bit-exact, so the NaN given is matched, but not NaNs with
+    // other bits, and negative zero is a reading of its own.
+    assert_eq!(Sample::from(f32::NAN), Sample::Missing);
+    assert!(matches!(Sample::from(-f32::NAN), Sample::Reading(value) if value.is_nan()));
+    assert!(matches!(Sample::from(-0.0), Sample::Reading(value) if value.is_sign_negative()));
+
+    assert!(f32::from(Sample::Missing).is_nan());
+    assert_eq!(f32::from(Sample::Half), 0.5);
+    assert_eq!(f32::from(Sample::Reading(-2.0)), -2.0);
+}
+
+#[test]
+fn run() {
+    main()
+}
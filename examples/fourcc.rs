@@ -0,0 +1,28 @@
+use enum_other::other;
+
+/// Codecs of a media container, named by their four-character code.
+#[other([u8; 4])]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Codec {
+    Avc1 = *b"avc1",
+    // The dereference can be left out.
+    Hvc1 = b"hvc1",
+    #[other(alias = b"mp4v")]
+    Mp4a = b"mp4a",
+}
+
+fn main() {
+    assert_eq!(Codec::from(*b"avc1"), Codec::Avc1);
+    assert_eq!(Codec::from(*b"hvc1"), Codec::Hvc1);
+    assert_eq!(Codec::from(*b"mp4v"), Codec::Mp4a);
+    assert_eq!(Codec::from(*b"av01"), Codec::Other(*b"av01"));
+
+    assert_eq!(<[u8; 4]>::from(Codec::Hvc1), *b"hvc1");
+    assert_eq!(<[u8; 4]>::from(Codec::Other(*b"av01")), *b"av01");
+    assert_eq!(u32::from_be_bytes(Codec::Avc1.into()), 0x6176_6331);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,36 @@
+use std::panic;
+
+use enum_other::other;
+
+#[other((u8, u8, u8), from_iter, into_iter)]
+#[derive(Debug, PartialEq, Eq)]
+enum Color {
+    Red = (255, 0, 0),
+    Black = (0, 0, 0),
+}
+
+fn main() {
+    // Decoders reading one byte at a time collect the components.
+    let bytes = [255u8, 0, 0, 1, 2, 3];
+    let colors = bytes
+        .chunks(3)
+        .map(|chunk| chunk.iter().copied().collect::<Color>())
+        .collect::<Vec<_>>();
+    assert_eq!(colors, [Color::Red, Color::Other(1, 2, 3)]);
+    assert_eq!(Color::Black.into_iter().collect::<Color>(), Color::Black);
+
+    assert_eq!(Color::try_from_iter([0, 0, 0]), Some(Color::Black));
+    assert_eq!(Color::try_from_iter([0, 0]), None);
+    assert_eq!(Color::try_from_iter([0, 0, 0, 0]), None);
+
+    // Keep the expected panic out of the output.
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(|| [1u8].into_iter().collect::<Color>());
+    let _ = panic::take_hook();
+    assert!(result.is_err());
+}
+
+#[test]
+fn run() {
+    main()
+}
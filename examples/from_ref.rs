@@ -0,0 +1,66 @@
+use enum_other::other;
+
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    Nop = 0,
+    Load = 1..=3,
+    #[other(skip)]
+    Reserved = 9,
+}
+
+#[other((u8, char))]
+enum Key {
+    Escape = (27, 'e'),
+}
+
+#[other(String, from_ref)]
+enum Method {
+    Get = "GET",
+    Post = "POST",
+}
+
+#[other(String, Unknown { name }, from_ref)]
+enum Header {
+    Host = "host",
+    #[other(prefix = "x-")]
+    Custom,
+}
+
+fn header_name(header: &Header) -> &str {
+    header.into()
+}
+
+fn u8_values(opcodes: &[Opcode]) -> Vec<u8> {
+    opcodes.iter().map(u8::from).collect()
+}
+
+fn main() {
+    let opcodes = [
+        Opcode::Nop,
+        Opcode::Load(2),
+        Opcode::Reserved,
+        Opcode::Other(7),
+    ];
+    assert_eq!(u8_values(&opcodes), [0, 2, 9, 7]);
+    assert_eq!(opcodes[3], Opcode::Other(7));
+
+    assert_eq!(<(u8, char)>::from(&Key::Escape), (27, 'e'));
+    assert_eq!(<(u8, char)>::from(&Key::Other(1, 'a')), (1, 'a'));
+
+    let method = Method::from("PATCH");
+    assert_eq!(String::from(&method), "PATCH");
+    assert_eq!(String::from(&Method::Post), "POST");
+    assert_eq!(<&str>::from(&method), "PATCH");
+    assert_eq!(<&str>::from(&Method::Get), "GET");
+    assert_eq!(String::from(method), "PATCH");
+
+    assert_eq!(header_name(&Header::Host), "host");
+    assert_eq!(header_name(&Header::from("x-trace")), "x-trace");
+    assert_eq!(header_name(&Header::from("accept")), "accept");
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,57 @@
+use enum_other::other;
+
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum Major {
+    One = 1,
+    Two = 2,
+}
+
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum Minor {
+    Zero = 0,
+    One = 1,
+}
+
+#[other((u8, u8), from_tuple = (Major, Minor))]
+#[derive(Debug, PartialEq, Eq)]
+enum ProtocolVersion {
+    Http10 = (1, 0),
+    Http11 = (1, 1),
+    Http2 = (2, 0),
+}
+
+type Pair = (u8, u8);
+
+#[other(Pair, arity = 2, strict, from_tuple = (Major, Minor))]
+#[derive(Debug, PartialEq, Eq)]
+enum Supported {
+    Http11 = (1, 1),
+}
+
+fn main() {
+    assert_eq!(
+        ProtocolVersion::from((Major::One, Minor::One)),
+        ProtocolVersion::Http11
+    );
+    assert_eq!(
+        ProtocolVersion::from((Major::Two, Minor::Zero)),
+        ProtocolVersion::Http2
+    );
+    assert_eq!(
+        ProtocolVersion::from((Major::Other(3), Minor::Zero)),
+        ProtocolVersion::Other(3, 0)
+    );
+
+    assert_eq!(
+        Supported::try_from((Major::One, Minor::One)),
+        Ok(Supported::Http11)
+    );
+    assert_eq!(Supported::try_from((Major::Two, Minor::One)), Err((2, 1)));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,23 @@
+use enum_other::other;
+
+#[deprecated]
+const LEGACY_VERSION: u8 = 1;
+
+#[other(u8)]
+#[other_impl_attr(allow(deprecated), cfg(all()))]
+#[derive(Debug, PartialEq, Eq)]
+enum Version {
+    V1 = LEGACY_VERSION,
+    V2 = 2,
+}
+
+fn main() {
+    assert_eq!(Version::from(2), Version::V2);
+    assert_eq!(Version::from(1), Version::V1);
+    assert_eq!(u8::from(Version::Other(3)), 3);
+}
+
+#[test]
+fn run() {
+    main()
+}
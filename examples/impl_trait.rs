@@ -0,0 +1,43 @@
+use enum_other::other;
+
+mod codec {
+    pub trait WireCodec {
+        fn encode(&self) -> u16;
+        fn decode(value: u16) -> Self;
+    }
+
+    pub trait Named {
+        fn to_wire(&self) -> String;
+    }
+}
+
+use codec::{Named, WireCodec};
+
+#[other(u16, impl_trait(path = crate::codec::WireCodec, encode = encode, decode = decode))]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+}
+
+#[other(String, impl_trait(path = codec::Named, encode = to_wire))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    Post = "POST",
+}
+
+fn main() {
+    assert_eq!(RecordType::Ns.encode(), 2);
+    assert_eq!(RecordType::Other(9).encode(), 9);
+    assert_eq!(RecordType::decode(1), RecordType::A);
+    assert_eq!(RecordType::decode(7), RecordType::Other(7));
+
+    assert_eq!(Method::Post.to_wire(), "POST");
+    assert_eq!(Method::Other("BREW".to_string()).to_wire(), "BREW");
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,37 @@
+use enum_other::other;
+
+#[other(usize, indexable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+#[other(usize, indexable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Head = 0,
+    Body = 1..=3,
+    Tail = 4,
+}
+
+fn main() {
+    let mut pixel = vec![0u8; 3];
+    pixel[Channel::Green] = 128;
+    pixel[Channel::Blue] += 1;
+    assert_eq!(pixel, [0, 128, 1]);
+    assert_eq!(pixel[Channel::Red], 0);
+
+    let slots = ["head", "a", "b", "c", "tail", "spare"];
+    assert_eq!(slots[Slot::Head], "head");
+    assert_eq!(slots[Slot::Body(2)], "b");
+    assert_eq!(slots[Slot::Tail], "tail");
+    assert_eq!(slots[Slot::Other(5)], "spare");
+    assert_eq!(slots[..][Slot::from(3)], "c");
+}
+
+#[test]
+fn run() {
+    main()
+}
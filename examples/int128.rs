@@ -0,0 +1,69 @@
+use enum_other::other;
+
+/// Scopes of multicast IPv6 addresses, as the address with the scope set.
+#[other(u128)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MulticastScope {
+    InterfaceLocal = 0xff01 << 112,
+    // Values above `i128::MAX` are not counted up from.
+    LinkLocal = 0xff02 << 112,
+    RealmLocal = 0xff03 << 112,
+    AdminLocal = 0xff04 << 112,
+    Global = 0xff0e_0000_0000_0000_0000_0000_0000_0000,
+    Reserved = 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+    NearlyReserved = u128::MAX - 1,
+}
+
+#[other(i128)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Offset {
+    Min = i128::MIN,
+    AfterMin = -170_141_183_460_469_231_731_687_303_715_884_105_727,
+    Huge = -(1 << 100),
+    LessHuge, // = -(1 << 100) + 1
+    Max = i128::MAX,
+}
+
+#[other(u64)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sentinel {
+    Invalid = 0xffff_ffff_ffff_fffe,
+    Unset, // = u64::MAX
+}
+
+fn main() {
+    assert_eq!(
+        MulticastScope::from(0xff01 << 112),
+        MulticastScope::InterfaceLocal
+    );
+    assert_eq!(
+        MulticastScope::from(0xff02 << 112),
+        MulticastScope::LinkLocal
+    );
+    assert_eq!(MulticastScope::from(0xff0e << 112), MulticastScope::Global);
+    assert_eq!(MulticastScope::from(u128::MAX), MulticastScope::Reserved);
+    assert_eq!(
+        MulticastScope::from(u128::MAX - 1),
+        MulticastScope::NearlyReserved
+    );
+    assert_eq!(
+        MulticastScope::from(u128::MAX - 2),
+        MulticastScope::Other(u128::MAX - 2)
+    );
+    assert_eq!(u128::from(MulticastScope::RealmLocal), 0xff03 << 112);
+
+    assert_eq!(Offset::from(i128::MIN), Offset::Min);
+    assert_eq!(Offset::from(i128::MIN + 1), Offset::AfterMin);
+    assert_eq!(Offset::from(-(1 << 100) + 1), Offset::LessHuge);
+    assert_eq!(Offset::from(i128::MAX), Offset::Max);
+    assert_eq!(Offset::from(-1), Offset::Other(-1));
+    assert_eq!(i128::from(Offset::LessHuge), -(1 << 100) + 1);
+
+    assert_eq!(Sentinel::from(u64::MAX), Sentinel::Unset);
+    assert_eq!(u64::from(Sentinel::Unset), u64::MAX);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,24 @@
+use enum_other::other;
+
+#[other((u8, u8, u8), into_iter)]
+#[derive(Debug, PartialEq, Eq)]
+enum Color {
+    Red = (255, 0, 0),
+    Teal = (0, 128, 128),
+}
+
+fn main() {
+    assert_eq!(Color::Red.into_iter().collect::<Vec<u8>>(), [255, 0, 0]);
+    assert_eq!(Color::Other(1, 2, 3).into_iter().sum::<u8>(), 6);
+
+    let mut channels = Vec::new();
+    for channel in Color::Teal {
+        channels.push(channel);
+    }
+    assert_eq!(channels, [0, 128, 128]);
+}
+
+#[test]
+fn run() {
+    main()
+}
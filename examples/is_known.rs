@@ -0,0 +1,53 @@
+use enum_other::other;
+
+const PRIVATE: u16 = 0xff00;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    #[other(alias = 3)]
+    Cname = 5,
+    Extended = PRIVATE + 1,
+    Reserved = 10..20,
+    Private = PRIVATE + 2..=PRIVATE + 8,
+}
+
+const _: () = assert!(RecordType::is_known_value(2));
+
+#[other(String, case_insensitive)]
+#[derive(Debug, PartialEq, Eq)]
+enum Encoding {
+    Gzip = "gzip",
+    #[other(prefix = "x-")]
+    Extension,
+}
+
+#[other((u8, u8))]
+#[derive(Debug, PartialEq, Eq)]
+enum Version {
+    First = (1, 0),
+}
+
+fn main() {
+    assert!(RecordType::is_known_value(3));
+    assert!(RecordType::is_known_value(0xff01));
+    assert!(RecordType::is_known_value(15));
+    assert!(!RecordType::is_known_value(20));
+    assert!(RecordType::is_known_value(0xff08));
+    assert!(!RecordType::is_known_value(0xff09));
+    assert!(!RecordType::is_known_value(4));
+
+    assert!(Encoding::is_known_str("GZIP"));
+    assert!(Encoding::is_known_str("X-Custom"));
+    assert!(!Encoding::is_known_str("br"));
+
+    assert!(Version::is_known_value((1, 0)));
+    assert!(!Version::is_known_value((2, 0)));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,41 @@
+use std::convert::TryFrom;
+
+use enum_other::other;
+
+#[other(u16)]
+#[repr(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    Aaaa = 28,
+}
+
+#[other(u8, strict)]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Opcode {
+    Query = 0,
+    Notify = 4,
+    Update = 5,
+}
+
+/// Reads the tag, which a primitive representation places first.
+fn tag(value: &RecordType) -> u16 {
+    unsafe { *(value as *const RecordType as *const u16) }
+}
+
+fn main() {
+    assert_eq!(tag(&RecordType::Ns), 2);
+    assert_eq!(tag(&RecordType::Aaaa), 28);
+    assert_eq!(tag(&RecordType::Other(7)), 29);
+    assert_eq!(RecordType::from(28), RecordType::Aaaa);
+
+    assert_eq!(Opcode::Notify as u8, 4);
+    assert_eq!(Opcode::try_from(5), Ok(Opcode::Update));
+}
+
+#[test]
+fn run() {
+    main()
+}
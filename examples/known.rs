@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use enum_other::other;
+
+const PRIVATE: u16 = 0xff00;
+
+#[other(u16, known = DnsRecordTypeKnown, known_attrs(derive(Debug, Clone, Copy, PartialEq, Eq)))]
+#[derive(Debug, PartialEq, Eq)]
+enum DnsRecordType {
+    /// An IPv4 address.
+    A = 1,
+    Ns = 2,
+    #[other(alias = 3)]
+    Cname = 5,
+    Extended = PRIVATE + 1,
+    Reserved = 10..20,
+}
+
+fn main() {
+    assert_eq!(DnsRecordTypeKnown::Cname as u16, 5);
+    assert_eq!(DnsRecordTypeKnown::Extended as u16, 0xff01);
+    assert_eq!(std::mem::size_of::<DnsRecordTypeKnown>(), 2);
+
+    assert_eq!(
+        DnsRecordType::from(DnsRecordTypeKnown::Ns),
+        DnsRecordType::Ns
+    );
+    assert_eq!(
+        DnsRecordTypeKnown::try_from(DnsRecordType::A),
+        Ok(DnsRecordTypeKnown::A)
+    );
+    assert_eq!(
+        DnsRecordTypeKnown::try_from(DnsRecordType::Reserved(12)),
+        Err(DnsRecordType::Reserved(12))
+    );
+    assert_eq!(
+        DnsRecordTypeKnown::try_from(DnsRecordType::Other(7)),
+        Err(DnsRecordType::Other(7))
+    );
+
+    assert_eq!(
+        DnsRecordTypeKnown::try_from(3),
+        Ok(DnsRecordTypeKnown::Cname)
+    );
+    assert_eq!(
+        DnsRecordTypeKnown::try_from(0xff01),
+        Ok(DnsRecordTypeKnown::Extended)
+    );
+    assert_eq!(DnsRecordTypeKnown::try_from(12), Err(12));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,44 @@
+use enum_other::other;
+
+const ACK: u8 = 0x40;
+
+/// The type code sits in the high nibble, with flags in the low bits.
+#[other(u8, mask = 0xF0)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Packet {
+    Connect = 0x10,
+    Publish = 0x30,
+    #[other(alias = 0x50)]
+    Ack = ACK,
+}
+
+#[other(u8, mask = 0xF0, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum Code {
+    Data = 0x00,
+    Control = 0x80,
+}
+
+fn main() {
+    // Set flag bits are ignored when matching, but not kept by the variant.
+    assert_eq!(Packet::from(0x10), Packet::Connect);
+    assert_eq!(Packet::from(0x3b), Packet::Publish);
+    assert_eq!(u8::from(Packet::from(0x3b)), 0x30);
+    assert_eq!(Packet::from(0x41), Packet::Ack);
+    assert_eq!(Packet::from(0x52), Packet::Ack);
+
+    // Unknown codes keep the whole byte, flags included.
+    assert_eq!(Packet::from(0xe3), Packet::Other(0xe3));
+    assert_eq!(u8::from(Packet::from(0xe3)), 0xe3);
+    assert!(Packet::is_known_value(0x37));
+    assert!(!Packet::is_known_value(0x07));
+
+    assert_eq!(Code::try_from(0x8f), Ok(Code::Control));
+    assert_eq!(Code::try_from(0x0f), Ok(Code::Data));
+    assert_eq!(Code::try_from(0x4f), Err(0x4f));
+}
+
+#[test]
+fn run() {
+    main()
+}
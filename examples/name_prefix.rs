@@ -0,0 +1,57 @@
+use enum_other::other;
+
+#[other(String, prefix = "HTTP_METHOD_")]
+#[derive(Debug, PartialEq, Eq)]
+enum HttpMethod {
+    Get,
+    Post,
+    NotAllowed,
+    Custom = "X_CUSTOM",
+}
+
+#[other(String, prefix = "HTTP_METHOD_", rename_all = "kebab-case")]
+#[derive(Debug, PartialEq, Eq)]
+enum KebabMethod {
+    Get,
+    NotAllowed,
+}
+
+#[other(String, prefix = "httpMethod", rename_all = "snake_case")]
+#[derive(Debug, PartialEq, Eq)]
+enum SnakeMethod {
+    Get,
+}
+
+#[other(String, prefix = "color.", rename_all = "kebab-case")]
+#[derive(Debug, PartialEq, Eq)]
+enum Color {
+    DarkRed,
+    #[other(rename = "light")]
+    White,
+}
+
+fn main() {
+    assert_eq!(String::from(HttpMethod::Post), "HTTP_METHOD_POST");
+    assert_eq!(
+        String::from(HttpMethod::NotAllowed),
+        "HTTP_METHOD_NOT_ALLOWED"
+    );
+    assert_eq!(HttpMethod::from("HTTP_METHOD_GET"), HttpMethod::Get);
+    assert_eq!(HttpMethod::from("X_CUSTOM"), HttpMethod::Custom);
+    assert_eq!(HttpMethod::from("GET"), HttpMethod::Other("GET".to_owned()));
+
+    assert_eq!(String::from(KebabMethod::Get), "http-method-get");
+    assert_eq!(
+        KebabMethod::from("http-method-not-allowed"),
+        KebabMethod::NotAllowed
+    );
+    assert_eq!(String::from(SnakeMethod::Get), "http_method_get");
+
+    assert_eq!(String::from(Color::DarkRed), "color.dark-red");
+    assert_eq!(Color::from("light"), Color::White);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,64 @@
+use enum_other::other;
+
+#[other(u16, Unrecognized { code })]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    Nop = 0,
+    Halt = 1,
+}
+
+#[other((u8, u8, u8), Custom { red, green, blue })]
+#[derive(Debug, PartialEq, Eq)]
+enum Color {
+    Black = (0, 0, 0),
+    White = (255, 255, 255),
+}
+
+#[other((u8, u8), named_other = [major, minor])]
+#[derive(Debug, PartialEq, Eq)]
+enum Version {
+    First = (1, 0),
+}
+
+#[other(String, Unknown { name }, display)]
+#[derive(Debug, PartialEq, Eq)]
+enum Shell {
+    Bash,
+    Zsh,
+}
+
+fn main() {
+    assert_eq!(Opcode::from(1), Opcode::Halt);
+    assert_eq!(Opcode::from(7), Opcode::Unrecognized { code: 7 });
+    assert_eq!(u16::from(Opcode::Unrecognized { code: 9 }), 9);
+    assert_eq!(Opcode::Unrecognized { code: 9 }.into_other(), Some(9));
+
+    let orange = Color::Custom {
+        red: 255,
+        green: 165,
+        blue: 0,
+    };
+    assert_eq!(Color::from((255, 165, 0)), orange);
+    assert_eq!(<(u8, u8, u8)>::from(orange), (255, 165, 0));
+    assert_eq!(Color::from((0, 0, 0)), Color::Black);
+
+    assert_eq!(Version::from((2, 1)), Version::Other { major: 2, minor: 1 });
+    assert_eq!(
+        <(u8, u8)>::from(Version::Other { major: 3, minor: 0 }),
+        (3, 0)
+    );
+
+    let fish = Shell::Unknown {
+        name: "fish".to_owned(),
+    };
+    assert_eq!(Shell::from("fish"), fish);
+    assert_eq!(Shell::from("Zsh".to_owned()), Shell::Zsh);
+    assert_eq!(fish.label(), None);
+    assert_eq!(fish.to_string(), "fish");
+    assert_eq!(String::from(fish), "fish");
+}
+
+#[test]
+fn run() {
+    main()
+}
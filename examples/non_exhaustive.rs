@@ -0,0 +1,46 @@
+use enum_other::other;
+
+#[other(u8)]
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq)]
+enum ContentType {
+    ChangeCipherSpec = 20,
+    Alert = 21,
+    Handshake = 22,
+    ApplicationData = 23,
+}
+
+#[other(u16, Unassigned, non_exhaustive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipherSuite {
+    Aes128GcmSha256 = 0x1301,
+    Aes256GcmSha384 = 0x1302,
+}
+
+fn describe(content_type: ContentType) -> &'static str {
+    match content_type {
+        ContentType::Handshake => "handshake",
+        ContentType::ApplicationData => "data",
+        ContentType::Other(_) => "unknown",
+        _ => "control",
+    }
+}
+
+fn main() {
+    assert_eq!(ContentType::from(22), ContentType::Handshake);
+    assert_eq!(u8::from(ContentType::Alert), 21);
+    assert_eq!(ContentType::from(24), ContentType::Other(24));
+
+    assert_eq!(describe(ContentType::from(23)), "data");
+    assert_eq!(describe(ContentType::from(21)), "control");
+    assert_eq!(describe(ContentType::from(20)), "control");
+    assert_eq!(describe(ContentType::from(99)), "unknown");
+
+    assert_eq!(CipherSuite::from(0x1302), CipherSuite::Aes256GcmSha384);
+    assert_eq!(u16::from(CipherSuite::Unassigned(7)), 7);
+}
+
+#[test]
+fn run() {
+    main()
+}
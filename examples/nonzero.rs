@@ -0,0 +1,53 @@
+use std::{
+    mem,
+    num::{NonZeroI8, NonZeroU16},
+};
+
+use enum_other::other;
+
+/// Well-known ports, as port zero is never used on the wire.
+#[other(NonZeroU16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Port {
+    Ftp = 21,
+    Ssh,
+    #[other(alias = 8080)]
+    Http = 80,
+    Https = 443,
+}
+
+/// Steps counted up from one when not given.
+#[other(core::num::NonZeroI8, strict)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Forward,
+    Twice,
+    Back = -1,
+}
+
+fn port(value: u16) -> NonZeroU16 {
+    NonZeroU16::new(value).unwrap()
+}
+
+fn main() {
+    assert_eq!(Port::from(port(22)), Port::Ssh);
+    assert_eq!(Port::from(port(8080)), Port::Http);
+    assert_eq!(Port::from(port(3000)), Port::Other(port(3000)));
+    assert_eq!(NonZeroU16::from(Port::Https), port(443));
+    assert_eq!(NonZeroU16::from(Port::Other(port(3000))), port(3000));
+
+    let step = |value| NonZeroI8::new(value).unwrap();
+    assert_eq!(Step::try_from(step(1)), Ok(Step::Forward));
+    assert_eq!(Step::try_from(step(2)), Ok(Step::Twice));
+    assert_eq!(Step::try_from(step(-1)), Ok(Step::Back));
+    assert_eq!(Step::try_from(step(3)), Err(step(3)));
+    assert_eq!(NonZeroI8::from(Step::Twice), step(2));
+
+    // The other variant keeps the niche of the data type.
+    assert_eq!(mem::size_of::<Option<Port>>(), mem::size_of::<Port>());
+}
+
+#[test]
+fn run() {
+    main()
+}
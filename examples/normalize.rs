@@ -0,0 +1,52 @@
+use enum_other::other;
+
+mod mechanism {
+    use std::borrow::Cow;
+
+    /// Trims whitespace and strips any vendor prefix.
+    pub fn normalize(value: &str) -> Cow<'_, str> {
+        let value = value.trim();
+        Cow::Borrowed(value.strip_prefix("X-VENDOR-").unwrap_or(value))
+    }
+}
+
+#[other(String, normalize = mechanism::normalize, case_insensitive)]
+#[derive(Debug, PartialEq, Eq)]
+enum Mechanism {
+    Plain = "PLAIN",
+    External = "EXTERNAL",
+}
+
+#[other(String, normalize = mechanism::normalize, store_normalized)]
+#[derive(Debug, PartialEq, Eq)]
+enum StoredMechanism {
+    Plain = "PLAIN",
+}
+
+fn main() {
+    assert_eq!(Mechanism::from("PLAIN".to_string()), Mechanism::Plain);
+    assert_eq!(Mechanism::from("  plain\r\n".to_string()), Mechanism::Plain);
+    assert_eq!(
+        Mechanism::from("X-VENDOR-External".to_string()),
+        Mechanism::External,
+    );
+    assert_eq!(
+        Mechanism::from(" X-VENDOR-GSSAPI ".to_string()),
+        Mechanism::Other(" X-VENDOR-GSSAPI ".to_string()),
+    );
+    assert_eq!(String::from(Mechanism::External), "EXTERNAL");
+
+    assert_eq!(
+        StoredMechanism::from(" X-VENDOR-PLAIN".to_string()),
+        StoredMechanism::Plain,
+    );
+    assert_eq!(
+        StoredMechanism::from(" X-VENDOR-GSSAPI ".to_string()),
+        StoredMechanism::Other("GSSAPI".to_string()),
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
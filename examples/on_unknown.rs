@@ -0,0 +1,64 @@
+use std::{borrow::Cow, sync::atomic::Ordering};
+
+use enum_other::other;
+
+mod metrics {
+    use std::sync::{
+        atomic::{AtomicU16, AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    pub static UNKNOWN_RECORD_TYPES: AtomicUsize = AtomicUsize::new(0);
+    pub static LAST_RECORD_TYPE: AtomicU16 = AtomicU16::new(0);
+    pub static SCHEMES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    pub fn record_unknown_record_type(value: &u16) {
+        UNKNOWN_RECORD_TYPES.fetch_add(1, Ordering::Relaxed);
+        LAST_RECORD_TYPE.store(*value, Ordering::Relaxed);
+    }
+
+    pub fn record_unknown_scheme(value: &str) {
+        SCHEMES.lock().unwrap().push(value.to_owned());
+    }
+}
+
+fn lowercase(value: &str) -> Cow<'_, str> {
+    Cow::Owned(value.to_lowercase())
+}
+
+#[other(u16, on_unknown = metrics::record_unknown_record_type)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+}
+
+#[other(String, on_unknown = metrics::record_unknown_scheme, normalize = lowercase, store_normalized)]
+#[derive(Debug, PartialEq, Eq)]
+enum Scheme {
+    Http = "http",
+}
+
+fn main() {
+    assert_eq!(RecordType::from(1), RecordType::A);
+    assert_eq!(metrics::UNKNOWN_RECORD_TYPES.load(Ordering::Relaxed), 0);
+    assert_eq!(RecordType::from(99), RecordType::Other(99));
+    assert_eq!(RecordType::from(98), RecordType::Other(98));
+    assert_eq!(metrics::UNKNOWN_RECORD_TYPES.load(Ordering::Relaxed), 2);
+    assert_eq!(metrics::LAST_RECORD_TYPE.load(Ordering::Relaxed), 98);
+    assert_eq!(RecordType::try_from_known(97), None);
+    assert_eq!(metrics::UNKNOWN_RECORD_TYPES.load(Ordering::Relaxed), 2);
+
+    assert_eq!(Scheme::from("HTTP"), Scheme::Http);
+    assert_eq!(Scheme::from("FTP"), Scheme::Other("ftp".to_owned()));
+    assert_eq!(
+        Scheme::from("Gopher".to_owned()),
+        Scheme::Other("gopher".to_owned())
+    );
+    assert_eq!(*metrics::SCHEMES.lock().unwrap(), ["ftp", "gopher"]);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,68 @@
+use enum_other::other;
+
+#[other(u16, from_only)]
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Ok = 200,
+    NotFound = 404,
+}
+
+impl From<Status> for u16 {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::Ok => 200,
+            Status::NotFound => 404,
+            Status::Other(code) if code < 100 => 500,
+            Status::Other(code) => code,
+        }
+    }
+}
+
+#[other(String, into_only)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    Post = "POST",
+}
+
+impl From<String> for Method {
+    fn from(value: String) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "GET" => Self::Get,
+            "POST" => Self::Post,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+#[other(u8, no_impls)]
+#[derive(Debug, PartialEq, Eq)]
+enum Flag {
+    On = 1,
+}
+
+impl From<u8> for Flag {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other(0),
+            _ => Self::On,
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(Status::from(404), Status::NotFound);
+    assert_eq!(u16::from(Status::Other(42)), 500);
+    assert_eq!(Status::try_from_known(200), Some(Status::Ok));
+
+    assert_eq!(Method::from("get".to_owned()), Method::Get);
+    assert_eq!(String::from(Method::Other("PUT".to_owned())), "PUT");
+
+    assert_eq!(Flag::from(7), Flag::On);
+    assert_eq!(Flag::Other(3).into_other(), Some(3));
+}
+
+#[test]
+fn run() {
+    main()
+}
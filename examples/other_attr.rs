@@ -0,0 +1,60 @@
+use enum_other::other;
+
+#[other(u8)]
+#[other_attr(doc = "A frame type this crate does not know about.")]
+#[other_attr(deprecated = "match on the known frame types instead")]
+#[derive(Debug, PartialEq, Eq)]
+enum FrameType {
+    Data = 0,
+    Headers = 1,
+    Settings = 4,
+}
+
+#[other(u8)]
+#[other_attr(cfg(any()))]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    Continuation = 0,
+    Text = 1,
+    Binary = 2,
+}
+
+#[other(
+    u16,
+    Unassigned,
+    attrs(doc = "A record type without a known meaning.", doc(hidden))
+)]
+#[other_attr(deprecated)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+}
+
+fn main() {
+    assert_eq!(FrameType::from(4), FrameType::Settings);
+    assert_eq!(u8::from(FrameType::Headers), 1);
+    #[allow(deprecated)]
+    {
+        assert_eq!(FrameType::from(9), FrameType::Other(9));
+    }
+    assert_eq!(FrameType::from(9).into_other(), Some(9));
+
+    assert_eq!(RecordType::from(2), RecordType::Ns);
+    #[allow(deprecated)]
+    {
+        assert_eq!(RecordType::from(99), RecordType::Unassigned(99));
+    }
+
+    assert_eq!(u8::from(Opcode::Binary), 2);
+    assert_eq!(u8::from(Opcode::Text), 1);
+    assert_eq!(
+        Opcode::from_name("Continuation"),
+        Some(Opcode::Continuation)
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,49 @@
+use enum_other::other;
+
+#[other(u16)]
+#[other_mapping(text: String)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    #[other(text = "A")]
+    A = 1,
+    #[other(text = "NS")]
+    Ns = 2,
+    #[other(text = "CNAME")]
+    Cname = 5,
+    Opt = 41,
+}
+
+#[other(u8, strict)]
+#[other_mapping(symbol: char)]
+#[other_mapping(name: &'static str)]
+#[derive(Debug, PartialEq, Eq)]
+enum Operator {
+    #[other(symbol = '+', name = "plus")]
+    Add = 1,
+    #[other(symbol = '-', name = "minus")]
+    Sub = 2,
+}
+
+fn main() {
+    assert_eq!(RecordType::from(5), RecordType::Cname);
+    assert_eq!(RecordType::try_from("NS".to_owned()), Ok(RecordType::Ns));
+    assert_eq!(RecordType::try_from("MX".to_owned()), Err("MX".to_owned()));
+    assert_eq!(String::try_from(RecordType::A), Ok("A".to_owned()));
+    assert_eq!(String::try_from(RecordType::Opt), Err(RecordType::Opt));
+    assert_eq!(
+        String::try_from(RecordType::Other(99)),
+        Err(RecordType::Other(99))
+    );
+
+    assert_eq!(Operator::try_from('-'), Ok(Operator::Sub));
+    assert_eq!(Operator::try_from('*'), Err('*'));
+    assert_eq!(char::try_from(Operator::Add), Ok('+'));
+    assert_eq!(Operator::try_from("plus"), Ok(Operator::Add));
+    assert_eq!(<&str>::try_from(Operator::Sub), Ok("minus"));
+    assert_eq!(Operator::try_from(2u8), Ok(Operator::Sub));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,48 @@
+use enum_other::{other, other_newtype};
+
+#[other(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordType {
+    A = 1,
+    Ns = 2,
+    Aaaa = 28,
+}
+
+/// Every conversion of the enum is forwarded.
+#[other_newtype(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryType(RecordType);
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Method {
+    Get = "GET",
+}
+
+/// Named fields work the same.
+#[other_newtype(String)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Request {
+    method: Method,
+}
+
+fn main() {
+    assert_eq!(QueryType::from(28), QueryType(RecordType::Aaaa));
+    assert_eq!(QueryType::from(99), QueryType(RecordType::Other(99)));
+    assert_eq!(u16::from(QueryType(RecordType::Ns)), 2);
+    assert_eq!(QueryType::from(RecordType::A), QueryType(RecordType::A));
+    assert_eq!(RecordType::from(QueryType::from(1)), RecordType::A);
+
+    assert_eq!(
+        Request::from("GET".to_string()),
+        Request {
+            method: Method::Get
+        }
+    );
+    assert_eq!(String::from(Request::from("PUT".to_string())), "PUT");
+}
+
+#[test]
+fn run() {
+    main()
+}
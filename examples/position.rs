@@ -0,0 +1,41 @@
+use enum_other::other;
+
+#[other(u8, position = first)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Debug = 1,
+    Info = 2,
+    Warn = 3,
+}
+
+#[other(u8, Unknown, position = 1)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Grade {
+    Fail = 0,
+    Pass = 1,
+    Merit = 2,
+}
+
+#[other(u8, position = last)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Size {
+    Small = 1,
+    Large = 2,
+}
+
+fn main() {
+    assert!(Level::Other(9) < Level::Debug);
+    assert_eq!(Level::from(2), Level::Info);
+    assert_eq!(u8::from(Level::Other(9)), 9);
+
+    assert!(Grade::Fail < Grade::Unknown(5));
+    assert!(Grade::Unknown(5) < Grade::Pass);
+    assert_eq!(Grade::from(5), Grade::Unknown(5));
+
+    assert!(Size::Large < Size::Other(0));
+}
+
+#[test]
+fn run() {
+    main()
+}
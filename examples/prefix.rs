@@ -0,0 +1,59 @@
+use enum_other::other;
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum HeaderName {
+    Accept = "Accept",
+    #[other(prefix = "X-")]
+    Extension,
+    #[other(prefix = "X-Amz-")]
+    Amazon,
+    ForwardedFor = "X-Forwarded-For",
+}
+
+#[other(String, case_insensitive)]
+#[derive(Debug, PartialEq, Eq)]
+enum Mechanism {
+    Plain = "PLAIN",
+    #[other(prefix = "SCRAM-")]
+    Scram,
+}
+
+fn main() {
+    assert_eq!(
+        HeaderName::from("X-Request-Id".to_string()),
+        HeaderName::Extension("X-Request-Id".to_string()),
+    );
+    assert_eq!(
+        HeaderName::from("X-Amz-Date".to_string()),
+        HeaderName::Amazon("X-Amz-Date".to_string()),
+    );
+    assert_eq!(
+        HeaderName::from("X-Forwarded-For".to_string()),
+        HeaderName::ForwardedFor,
+    );
+    assert_eq!(HeaderName::from("Accept".to_string()), HeaderName::Accept);
+    assert_eq!(
+        HeaderName::from("Y-Header".to_string()),
+        HeaderName::Other("Y-Header".to_string()),
+    );
+    assert_eq!(
+        String::from(HeaderName::Extension("X-Trace".to_string())),
+        "X-Trace",
+    );
+
+    assert_eq!(
+        Mechanism::from("scram-sha-256".to_string()),
+        Mechanism::Scram("scram-sha-256".to_string()),
+    );
+    assert_eq!(Mechanism::from("plain".to_string()), Mechanism::Plain);
+    assert_eq!(
+        Mechanism::from("SCRAM".to_string()),
+        Mechanism::Other("SCRAM".to_string()),
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
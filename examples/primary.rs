@@ -0,0 +1,57 @@
+use enum_other::other;
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    A = 1,
+    #[deprecated = "renamed to `Https`"]
+    SvcbHttps = 65,
+    #[other(primary)]
+    Https = 65,
+}
+
+#[other(u8, known = KnownOpcode, known_attrs(derive(Debug, PartialEq, Eq)))]
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    #[other(primary)]
+    Query = 0,
+    #[other(alias = 10)]
+    StandardQuery = 0,
+    Status = 2,
+}
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    #[other(primary)]
+    Delete = "DELETE",
+    #[other(alias = "DEL")]
+    Remove = "DELETE",
+}
+
+#[allow(deprecated)]
+fn main() {
+    assert_eq!(RecordType::from(65), RecordType::Https);
+    assert_eq!(u16::from(RecordType::Https), 65);
+    assert_eq!(u16::from(RecordType::SvcbHttps), 65);
+
+    assert_eq!(Opcode::from(0), Opcode::Query);
+    assert_eq!(Opcode::from(10), Opcode::StandardQuery);
+    assert_eq!(u8::from(Opcode::StandardQuery), 0);
+    assert_eq!(KnownOpcode::Query as u8, 0);
+    assert_eq!(
+        KnownOpcode::try_from(Opcode::StandardQuery),
+        Ok(KnownOpcode::Query)
+    );
+    assert_eq!(KnownOpcode::try_from(10), Ok(KnownOpcode::Query));
+
+    assert_eq!(Method::from("DELETE"), Method::Delete);
+    assert_eq!(Method::from("DEL"), Method::Remove);
+    assert_eq!(String::from(Method::Remove), "DELETE");
+}
+
+#[test]
+fn run() {
+    main()
+}
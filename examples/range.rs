@@ -0,0 +1,38 @@
+use enum_other::other;
+
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum HashAlgorithm {
+    None = 0,
+    Md5 = 1,
+    Sha1 = 2,
+    Sha256 = 4,
+    PrivateUse = 224..=255,
+    Intrinsic = 8,
+    Legacy = 9..12,
+}
+
+fn main() {
+    assert_eq!(HashAlgorithm::from(2), HashAlgorithm::Sha1);
+    assert_eq!(HashAlgorithm::from(230), HashAlgorithm::PrivateUse(230));
+    assert_eq!(u8::from(HashAlgorithm::PrivateUse(240)), 240);
+
+    assert_eq!(HashAlgorithm::from(10), HashAlgorithm::Legacy(10));
+    assert_eq!(HashAlgorithm::from(12), HashAlgorithm::Other(12));
+    assert_eq!(
+        HashAlgorithm::try_from_known(230),
+        Some(HashAlgorithm::PrivateUse(230)),
+    );
+    assert_eq!(HashAlgorithm::try_from_known(12), None);
+    assert_eq!(u8::from(HashAlgorithm::Intrinsic), 8);
+
+    assert_eq!(HashAlgorithm::from(3), HashAlgorithm::Other(3));
+
+    assert_eq!(HashAlgorithm::from_name("Md5"), Some(HashAlgorithm::Md5));
+    assert_eq!(HashAlgorithm::from_name("PrivateUse"), None);
+}
+
+#[test]
+fn run() {
+    main()
+}
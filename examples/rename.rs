@@ -0,0 +1,34 @@
+use enum_other::other;
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Header {
+    Accept = "Accept",
+    ContentType = "Content-Type",
+    #[other(rename = "X-Forwarded-For")]
+    ForwardedFor,
+    #[other(rename = "X-Request-ID", alias = "X-Request-Id")]
+    RequestId = "Request-ID",
+}
+
+fn main() {
+    assert_eq!(String::from(Header::ContentType), "Content-Type");
+    assert_eq!(String::from(Header::ForwardedFor), "X-Forwarded-For");
+    assert_eq!(String::from(Header::RequestId), "X-Request-ID");
+
+    assert_eq!(
+        Header::from("X-Forwarded-For".to_string()),
+        Header::ForwardedFor,
+    );
+    assert_eq!(Header::from("X-Request-Id".to_string()), Header::RequestId);
+    assert_eq!(
+        Header::from("Request-ID".to_string()),
+        Header::Other("Request-ID".to_string()),
+    );
+    assert_eq!(Header::from("Accept".to_string()), Header::Accept);
+}
+
+#[test]
+fn run() {
+    main()
+}
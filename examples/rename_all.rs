@@ -0,0 +1,46 @@
+use enum_other::other;
+
+#[other(String, rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Ok,
+    NotFound,
+    PermissionDenied,
+    #[other(rename = "DEADLINE")]
+    DeadlineExceeded,
+    Cancelled = "CANCELED",
+}
+
+#[other(String, Unknown, rename_all = "camelCase")]
+#[derive(Debug, PartialEq, Eq)]
+enum Field {
+    UserName,
+    CreatedAt,
+    Id,
+}
+
+fn main() {
+    assert_eq!(String::from(Status::Ok), "OK");
+    assert_eq!(String::from(Status::PermissionDenied), "PERMISSION_DENIED");
+    assert_eq!(String::from(Status::DeadlineExceeded), "DEADLINE");
+    assert_eq!(String::from(Status::Cancelled), "CANCELED");
+
+    assert_eq!(Status::from("NOT_FOUND".to_string()), Status::NotFound);
+    assert_eq!(
+        Status::from("NotFound".to_string()),
+        Status::Other("NotFound".to_string()),
+    );
+
+    assert_eq!(String::from(Field::UserName), "userName");
+    assert_eq!(String::from(Field::Id), "id");
+    assert_eq!(Field::from("createdAt".to_string()), Field::CreatedAt);
+    assert_eq!(
+        Field::from("CreatedAt".to_string()),
+        Field::Unknown("CreatedAt".to_string()),
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
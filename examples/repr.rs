@@ -0,0 +1,36 @@
+use std::mem::size_of;
+
+use enum_other::other;
+
+#[other(u8, repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IcmpType {
+    EchoReply = 0,
+    DestinationUnreachable = 3,
+    EchoRequest = 8,
+}
+
+#[other(u16, repr)]
+#[repr(C, u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Port {
+    Http = 80,
+    Https = 443,
+}
+
+fn main() {
+    assert_eq!(size_of::<IcmpType>(), 2);
+    assert_eq!(IcmpType::from(8), IcmpType::EchoRequest);
+    assert_eq!(u8::from(IcmpType::DestinationUnreachable), 3);
+    assert_eq!(IcmpType::from(42), IcmpType::Other(42));
+
+    assert_eq!(size_of::<Port>(), 4);
+    assert_eq!(Port::from(443), Port::Https);
+    assert_eq!(u16::from(Port::Http), 80);
+    assert_eq!(Port::from(8080), Port::Other(8080));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,46 @@
+use enum_other::{assert_round_trip, other};
+
+#[other(u16)]
+#[derive(Debug)]
+enum RecordType {
+    A = 1,
+    Ns = 2,
+    Cname = 5,
+    Private = 0xff00..=0xffff,
+}
+
+#[other((u8, u8))]
+#[derive(Debug)]
+enum Version {
+    First = (1, 0),
+    Second = (2, 0),
+}
+
+#[other(String, rename_all = "lowercase")]
+#[derive(Debug)]
+enum Encoding {
+    Gzip,
+    Deflate,
+}
+
+fn main() {
+    assert_round_trip!(RecordType, [1, 2, 5, 0xff10, 300]);
+    assert_round_trip!(Version, [(1, 0), (2, 0), (3, 1)]);
+    assert_round_trip!(Encoding: String, ["gzip", "deflate", "br"]);
+
+    // Values are only evaluated once, so they can be moved or have effects.
+    let zstd = "zstd".to_string();
+    let mut next = 0;
+    let mut count = || {
+        next += 1;
+        next
+    };
+    assert_round_trip!(Encoding: String, [zstd]);
+    assert_round_trip!(RecordType, [count(), count()]);
+    assert_eq!(next, 2);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,40 @@
+use std::convert::TryFrom;
+
+use enum_other::other;
+
+// A record type of zero is reserved, and stands for a missing one.
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum RecordType {
+    #[other(sentinel)]
+    Reserved = 0,
+    A = 1,
+    Aaaa = 28,
+}
+
+#[other(u8, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum Priority {
+    Low = 1,
+    High = 2,
+    #[other(skip, sentinel)]
+    Unset,
+}
+
+fn main() {
+    assert_eq!(RecordType::from(None), RecordType::Reserved);
+    assert_eq!(RecordType::from(Some(0)), RecordType::Reserved);
+    assert_eq!(RecordType::from(Some(28)), RecordType::Aaaa);
+    assert_eq!(RecordType::from(Some(99)), RecordType::Other(99));
+    assert_eq!(Option::<u16>::from(RecordType::Reserved), None);
+    assert_eq!(Option::<u16>::from(RecordType::A), Some(1));
+
+    assert_eq!(Priority::try_from(None), Ok(Priority::Unset));
+    assert_eq!(Priority::try_from(Some(2)), Ok(Priority::High));
+    assert_eq!(Priority::try_from(Some(9)), Err(9));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use enum_other::other;
+
+fn lowercase(value: &str) -> Cow<'_, str> {
+    Cow::Owned(value.to_lowercase())
+}
+
+#[other(u16)]
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Hello = 1,
+    Ping = 2,
+    #[other(since = 2)]
+    Resume = 3,
+    #[other(since = 3, alias = 40)]
+    Compress = 4,
+    #[other(since = 3)]
+    Extension = 0x100..=0x1ff,
+}
+
+#[other(String, normalize = lowercase, store_normalized)]
+#[derive(Debug, PartialEq, Eq)]
+enum Capability {
+    Auth = "auth",
+    #[other(since = 2)]
+    Pipelining = "pipelining",
+}
+
+fn main() {
+    assert_eq!(Command::from_with_version(2, 1), Command::Ping);
+    assert_eq!(Command::from_with_version(3, 1), Command::Other(3));
+    assert_eq!(Command::from_with_version(3, 2), Command::Resume);
+    assert_eq!(Command::from_with_version(40, 2), Command::Other(40));
+    assert_eq!(Command::from_with_version(40, 3), Command::Compress);
+    assert_eq!(Command::from_with_version(0x120, 2), Command::Other(0x120));
+    assert_eq!(
+        Command::from_with_version(0x120, 3),
+        Command::Extension(0x120)
+    );
+    assert_eq!(Command::from_with_version(9, 3), Command::Other(9));
+
+    // Plain conversions ignore versions.
+    assert_eq!(Command::from(4), Command::Compress);
+    assert_eq!(u16::from(Command::Compress), 4);
+
+    assert_eq!(
+        Capability::from_with_version("PIPELINING".to_owned(), 1),
+        Capability::Other("pipelining".to_owned())
+    );
+    assert_eq!(
+        Capability::from_with_version("PIPELINING".to_owned(), 2),
+        Capability::Pipelining
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
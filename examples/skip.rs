@@ -0,0 +1,37 @@
+use enum_other::other;
+
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum Frame {
+    Data = 0,
+    Headers = 1,
+    #[other(skip)]
+    Unparsed(Vec<u8>),
+    Priority = 2,
+}
+
+#[other(u8)]
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Ping = 1,
+    #[other(skip)]
+    Pong {
+        payload: u64,
+    } = 1,
+    Quit,
+}
+
+fn main() {
+    assert_eq!(Frame::from(2), Frame::Priority);
+    assert_eq!(Frame::from(3), Frame::Other(3));
+    assert_ne!(Frame::Unparsed(vec![2]), Frame::Priority);
+
+    assert_eq!(Command::from(1), Command::Ping);
+    assert_eq!(Command::from(2), Command::Quit);
+    assert_eq!(u8::from(Command::Pong { payload: 7 }), 1);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,49 @@
+use std::{mem, sync::Arc};
+
+use enum_other::other;
+
+#[other(String, store = Box<str>)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scheme {
+    Http = "http",
+    Https = "https",
+}
+
+#[other(String, store = Arc<str>, display, from_ref)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Header {
+    Host = "host",
+}
+
+/// Other types only need to convert back and forth.
+#[other(Vec<u8>, store = Box<[u8]>, from_ref)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Magic {
+    Png = b"\x89PNG",
+}
+
+fn main() {
+    assert!(mem::size_of::<Header>() < mem::size_of::<String>());
+    assert_eq!(Scheme::from("ftp".to_string()), Scheme::Other("ftp".into()));
+    assert_eq!(Scheme::from("ftp"), Scheme::Other("ftp".into()));
+    assert_eq!(String::from(Scheme::Other("ftp".into())), "ftp");
+    assert_eq!(Scheme::from("ftp").into_other(), Some(Box::from("ftp")));
+
+    let header = Header::from("x-trace");
+    assert_eq!(header, Header::Other(Arc::from("x-trace")));
+    assert_eq!(header.to_string(), "x-trace");
+    assert_eq!(String::from(&header), "x-trace");
+    assert_eq!(String::from(Header::Host), "host");
+
+    assert_eq!(
+        Magic::from(b"GIF8".to_vec()),
+        Magic::Other(Box::from(*b"GIF8"))
+    );
+    assert_eq!(Vec::from(&Magic::Other(Box::from(*b"GIF8"))), b"GIF8");
+    assert_eq!(Vec::from(Magic::Png), b"\x89PNG");
+}
+
+#[test]
+fn run() {
+    main()
+}
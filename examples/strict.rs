@@ -0,0 +1,46 @@
+use std::convert::TryFrom;
+
+use enum_other::other;
+
+#[other(String, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum HttpMethod {
+    Get = "GET",
+    Head = "HEAD",
+    Post = "POST",
+    Put = "PUT",
+    Delete = "DELETE",
+    Options = "OPTIONS",
+    Patch = "PATCH",
+}
+
+#[other(u8, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum Version {
+    V1 = 1,
+    V2, // = 2
+    Experimental = 0xf0..=0xff,
+}
+
+fn main() {
+    assert_eq!(String::from(HttpMethod::Delete), "DELETE");
+    assert_eq!(
+        HttpMethod::try_from("PATCH".to_string()),
+        Ok(HttpMethod::Patch),
+    );
+    assert_eq!(
+        HttpMethod::try_from("get".to_string()),
+        Err("get".to_string()),
+    );
+
+    assert_eq!(u8::from(Version::V2), 2);
+    assert_eq!(Version::try_from(1), Ok(Version::V1));
+    assert_eq!(Version::try_from(0xf3), Ok(Version::Experimental(0xf3)));
+    assert_eq!(Version::try_from(3), Err(3));
+    assert_eq!(Version::from_name("V2"), Some(Version::V2));
+}
+
+#[test]
+fn run() {
+    main()
+}
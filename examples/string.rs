@@ -15,6 +15,11 @@ enum HttpMethod {
 fn main() {
     assert_eq!(String::from(HttpMethod::Put), "PUT");
     assert_eq!(HttpMethod::from("GET".to_string()), HttpMethod::Get);
+    assert_eq!(HttpMethod::from("HEAD"), HttpMethod::Head);
+    assert_eq!(
+        HttpMethod::from("BREW"),
+        HttpMethod::Other("BREW".to_string()),
+    );
 
     assert_eq!(
         String::from(HttpMethod::Other("CONNECT".to_string())),
@@ -24,6 +29,11 @@ fn main() {
         HttpMethod::from("TRACE".to_string()),
         HttpMethod::Other("TRACE".to_string()),
     );
+
+    assert_eq!(
+        HttpMethod::from("TRACE".to_string()).try_into_known(),
+        Err("TRACE".to_string()),
+    );
 }
 
 #[test]
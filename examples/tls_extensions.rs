@@ -0,0 +1,51 @@
+use enum_other::other;
+
+/// Types of TLS handshake extensions, which are two bytes on the wire.
+#[other([u8; 2])]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExtensionType {
+    ServerName = [0x00, 0x00],
+    MaxFragmentLength = [0x00, 0x01],
+    SupportedGroups = [0x00, 0x0a],
+    // Byte strings and suffixed elements can be mixed with plain arrays.
+    Alpn = b"\x00\x10",
+    // Drafts used another codepoint.
+    #[other(alias = [0xff, 0xce])]
+    EncryptedClientHello = [0xfe, 0x0du8],
+}
+
+fn main() {
+    assert_eq!(ExtensionType::from([0x00, 0x00]), ExtensionType::ServerName);
+    assert_eq!(
+        ExtensionType::from([0x00, 0x0a]),
+        ExtensionType::SupportedGroups
+    );
+    assert_eq!(ExtensionType::from(*b"\x00\x10"), ExtensionType::Alpn);
+    assert_eq!(
+        ExtensionType::from([0xff, 0xce]),
+        ExtensionType::EncryptedClientHello
+    );
+    assert_eq!(
+        ExtensionType::from([0xff, 0x01]),
+        ExtensionType::Other([0xff, 0x01])
+    );
+
+    assert_eq!(
+        <[u8; 2]>::from(ExtensionType::MaxFragmentLength),
+        [0x00, 0x01]
+    );
+    assert_eq!(<[u8; 2]>::from(ExtensionType::Alpn), [0x00, 0x10]);
+    assert_eq!(
+        u16::from_be_bytes(ExtensionType::SupportedGroups.into()),
+        0x000a
+    );
+    assert_eq!(
+        <[u8; 2]>::from(ExtensionType::Other([0xff, 0x01])),
+        [0xff, 0x01]
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -22,6 +22,12 @@ fn main() {
         (255, 127, 0)
     );
     assert_eq!(Color::from((255, 0, 127)), Color::Other(255, 0, 127));
+
+    assert_eq!(Color::Other(255, 127, 0).into_other(), Some((255, 127, 0)));
+    assert_eq!(Color::Red.try_into_known(), Ok(Color::Red));
+
+    assert_eq!(Color::try_from_known((0, 255, 0)), Some(Color::Green));
+    assert_eq!(Color::try_from_known((1, 2, 3)), None);
 }
 
 #[test]
@@ -0,0 +1,39 @@
+use enum_other::other;
+
+#[other(u8, valid = 0..=15, invalid = Invalid)]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    Query = 0,
+    InverseQuery = 1,
+    Status = 2,
+    Reserved = 7..=15,
+}
+
+#[other(i8, display, valid = -8..8, invalid = OutOfRange)]
+#[derive(Debug, PartialEq, Eq)]
+enum Offset {
+    Back = -1,
+    Stay = 0,
+    Forward = 1,
+}
+
+fn main() {
+    assert_eq!(Opcode::from(2), Opcode::Status);
+    assert_eq!(Opcode::from(4), Opcode::Other(4));
+    assert_eq!(Opcode::from(9), Opcode::Reserved(9));
+    assert_eq!(Opcode::from(16), Opcode::Invalid(16));
+    assert_eq!(u8::from(Opcode::Invalid(200)), 200);
+    assert!(!Opcode::is_known_value(4));
+
+    assert_eq!(Offset::from(-1), Offset::Back);
+    assert_eq!(Offset::from(-8), Offset::Other(-8));
+    assert_eq!(Offset::from(-9), Offset::OutOfRange(-9));
+    assert_eq!(Offset::from(8), Offset::OutOfRange(8));
+    assert_eq!(i8::from(Offset::OutOfRange(-100)), -100);
+    assert_eq!(Offset::from(100).to_string(), "OutOfRange");
+}
+
+#[test]
+fn run() {
+    main()
+}
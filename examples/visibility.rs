@@ -0,0 +1,98 @@
+mod protocol {
+    use enum_other::other;
+
+    #[other(u8)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Public {
+        A = 1,
+    }
+
+    #[other(u8)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub(crate) enum Crate {
+        A = 1,
+    }
+
+    pub mod nested {
+        use enum_other::other;
+
+        #[other(u8)]
+        #[derive(Debug, PartialEq, Eq)]
+        pub(super) enum Super {
+            A = 1,
+        }
+
+        #[other(u8)]
+        #[derive(Debug, PartialEq, Eq)]
+        pub(in crate::protocol) enum InPath {
+            A = 1,
+        }
+
+        #[other(u8)]
+        #[derive(Debug, PartialEq, Eq)]
+        enum Private {
+            A = 1,
+        }
+
+        pub(crate) fn private_round_trip(value: u8) -> u8 {
+            assert_eq!(Private::from(1), Private::A);
+            u8::from(Private::from(value))
+        }
+    }
+
+    pub mod restricted {
+        use enum_other::other;
+
+        #[other(u8, vis = pub(super), known = RestrictedKnown)]
+        #[derive(Debug, PartialEq, Eq)]
+        pub enum Restricted {
+            A = 1,
+        }
+
+        #[other(u8, vis = pub(self))]
+        #[derive(Debug, PartialEq, Eq)]
+        pub enum Hidden {
+            A = 1,
+        }
+
+        pub(crate) fn hidden_known(value: u8) -> bool {
+            Hidden::is_known_value(value)
+        }
+    }
+
+    pub(crate) fn check() {
+        assert_eq!(nested::Super::from(1), nested::Super::A);
+        assert_eq!(nested::Super::from(2).into_other(), Some(2));
+        assert_eq!(nested::InPath::from(3), nested::InPath::Other(3));
+        assert!(nested::InPath::is_known_value(1));
+        assert_eq!(restricted::Restricted::from(1).into_other(), None);
+        assert_eq!(
+            restricted::Restricted::from(restricted::RestrictedKnown::A),
+            restricted::Restricted::A,
+        );
+    }
+}
+
+use protocol::{Crate, Public};
+
+fn main() {
+    assert_eq!(Public::from(1), Public::A);
+    assert_eq!(u8::from(Public::Other(4)), 4);
+    assert_eq!(Public::from_name("A"), Some(Public::A));
+
+    assert_eq!(Crate::from(5), Crate::Other(5));
+    assert_eq!(Crate::A.try_into_known(), Ok(Crate::A));
+
+    protocol::check();
+    assert_eq!(
+        protocol::restricted::Restricted::from(2),
+        protocol::restricted::Restricted::Other(2)
+    );
+    assert!(protocol::restricted::hidden_known(1));
+    assert_eq!(protocol::nested::private_round_trip(7), 7);
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,64 @@
+use std::num::{Saturating, Wrapping};
+
+use enum_other::other;
+
+/// Checksums of well-known payloads, computed with wrapping arithmetic.
+#[other(core::num::Wrapping<u16>)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Checksum {
+    Empty,
+    #[other(alias = 0xfffe)]
+    Blank = 0xffff,
+    Small = 0x10..=0xff,
+}
+
+#[other(Saturating<i8>, strict)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Min = -128,
+    Neutral = 0,
+    Max = 127,
+}
+
+/// A port number of our own, wrapping the value like `Wrapping` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Port(pub u16);
+
+#[other(Port, inner = u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Service {
+    Ssh = 22,
+    Http = 80,
+    Https, // = 81
+}
+
+fn main() {
+    let sum = Wrapping(0xfff0u16) + Wrapping(0x0f);
+    assert_eq!(Checksum::from(sum), Checksum::Blank);
+    assert_eq!(Checksum::from(sum - Wrapping(1)), Checksum::Blank);
+    assert_eq!(Checksum::from(sum + Wrapping(1)), Checksum::Empty);
+    assert_eq!(
+        Checksum::from(Wrapping(0x20)),
+        Checksum::Small(Wrapping(0x20))
+    );
+    assert_eq!(
+        Checksum::from(Wrapping(0x1234)),
+        Checksum::Other(Wrapping(0x1234))
+    );
+    assert_eq!(Wrapping::<u16>::from(Checksum::Blank), Wrapping(0xffff));
+
+    let level = Saturating(100i8) + Saturating(100);
+    assert_eq!(Level::try_from(level), Ok(Level::Max));
+    assert_eq!(Level::try_from(Saturating(1)), Err(Saturating(1)));
+    assert_eq!(Saturating::<i8>::from(Level::Min), Saturating(-128));
+
+    assert_eq!(Service::from(Port(22)), Service::Ssh);
+    assert_eq!(Service::from(Port(81)), Service::Https);
+    assert_eq!(Service::from(Port(8080)), Service::Other(Port(8080)));
+    assert_eq!(Port::from(Service::Http), Port(80));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use enum_other::other;
+
+// The enum is left as written, so it can still be shared with C.
+#[other(u16, Unknown, wrapper = MaybeRecordType, wrapper_attrs(derive(Debug, Clone, Copy, PartialEq, Eq)))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+enum DnsRecordType {
+    A = 1,
+    Ns = 2,
+    #[other(alias = 6)]
+    Cname = 5,
+    Aaaa = 28,
+    #[other(skip)]
+    Reserved = 0xffff,
+}
+
+fn main() {
+    assert_eq!(DnsRecordType::Aaaa as u16, 28);
+
+    assert_eq!(
+        MaybeRecordType::from(28),
+        MaybeRecordType::Known(DnsRecordType::Aaaa)
+    );
+    assert_eq!(
+        MaybeRecordType::from(6),
+        MaybeRecordType::Known(DnsRecordType::Cname)
+    );
+    assert_eq!(
+        MaybeRecordType::from(0xffff),
+        MaybeRecordType::Unknown(0xffff)
+    );
+    assert_eq!(MaybeRecordType::from(99), MaybeRecordType::Unknown(99));
+
+    assert_eq!(u16::from(MaybeRecordType::Known(DnsRecordType::Ns)), 2);
+    assert_eq!(
+        u16::from(MaybeRecordType::Known(DnsRecordType::Reserved)),
+        0xffff
+    );
+    assert_eq!(u16::from(MaybeRecordType::Unknown(99)), 99);
+
+    assert_eq!(
+        MaybeRecordType::from(DnsRecordType::A),
+        MaybeRecordType::Known(DnsRecordType::A)
+    );
+    assert_eq!(
+        DnsRecordType::try_from(MaybeRecordType::from(5)),
+        Ok(DnsRecordType::Cname)
+    );
+    assert_eq!(
+        DnsRecordType::try_from(MaybeRecordType::Unknown(7)),
+        Err(MaybeRecordType::Unknown(7)),
+    );
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1,32 @@
+use enum_other::other;
+
+#[other(u8, wrapping)]
+#[derive(Debug, PartialEq, Eq)]
+enum Sequence {
+    Late = 254,
+    Last,   // = 255
+    First,  // = 0
+    Second, // = 1
+}
+
+#[other(i8, wrapping)]
+#[derive(Debug, PartialEq, Eq)]
+enum Offset {
+    Max = 127,
+    Min, // = -128
+}
+
+fn main() {
+    assert_eq!(u8::from(Sequence::Last), 255);
+    assert_eq!(u8::from(Sequence::First), 0);
+    assert_eq!(Sequence::from(1), Sequence::Second);
+    assert_eq!(Sequence::from(2), Sequence::Other(2));
+
+    assert_eq!(i8::from(Offset::Min), -128);
+    assert_eq!(Offset::from(127), Offset::Max);
+}
+
+#[test]
+fn run() {
+    main()
+}
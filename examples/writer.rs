@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use enum_other::other;
+
+#[other(String, writer = StatusWriter)]
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Ok = "OK",
+    Error = "ERROR",
+}
+
+#[other(String, strict, writer = LevelWriter)]
+#[derive(Debug, PartialEq, Eq)]
+enum Level {
+    Debug = "debug",
+}
+
+fn main() {
+    // Responses arrive in pieces, and are only matched once complete.
+    let mut writer = StatusWriter::default();
+    for chunk in ["ER", "R", "OR"] {
+        writer.write_str(chunk).unwrap();
+    }
+    assert_eq!(writer.finish(), Status::Error);
+
+    let mut writer = StatusWriter::default();
+    write!(writer, "BUSY {}", 3).unwrap();
+    assert_eq!(writer.finish(), Status::Other("BUSY 3".to_owned()));
+
+    let mut writer = LevelWriter::default();
+    writer.write_str("debug").unwrap();
+    assert_eq!(writer.clone().finish(), Ok(Level::Debug));
+    writer.write_char('!').unwrap();
+    assert_eq!(writer.finish(), Err("debug!".to_owned()));
+}
+
+#[test]
+fn run() {
+    main()
+}
@@ -0,0 +1 @@
+//! Only holds the tests of the optional features, see `tests`.
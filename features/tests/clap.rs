@@ -0,0 +1,55 @@
+#![cfg(feature = "clap")]
+
+use clap::{Parser, ValueEnum};
+use enum_other::other;
+
+#[other(String, clap, rename_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    #[other(alias = "yml")]
+    Yaml,
+}
+
+#[other(String, clap, rename_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Speed {
+    #[other(primary)]
+    Fast,
+    Quick = "fast",
+    Slow,
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[arg(long, value_enum)]
+    format: OutputFormat,
+    #[arg(long, value_enum, default_value = "slow")]
+    speed: Speed,
+}
+
+#[test]
+fn parses_variants_and_aliases() {
+    let cli = Cli::parse_from(["cli", "--format", "json"]);
+    assert_eq!(cli.format, OutputFormat::Json);
+    assert_eq!(cli.speed, Speed::Slow);
+
+    let cli = Cli::parse_from(["cli", "--format", "yml", "--speed", "fast"]);
+    assert_eq!(cli.format, OutputFormat::Yaml);
+    assert_eq!(cli.speed, Speed::Fast);
+}
+
+#[test]
+fn rejects_other_values() {
+    assert!(Cli::try_parse_from(["cli", "--format", "xml"]).is_err());
+}
+
+#[test]
+fn lists_shared_values_once() {
+    let names = Speed::value_variants()
+        .iter()
+        .filter_map(ValueEnum::to_possible_value)
+        .map(|value| value.get_name().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["fast", "slow"]);
+}
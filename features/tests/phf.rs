@@ -0,0 +1,29 @@
+#![cfg(feature = "phf")]
+
+use enum_other::other;
+
+#[other(String, phf)]
+#[derive(Debug, PartialEq, Eq)]
+enum MediaType {
+    Html = "text/html",
+    #[other(alias = "text/json")]
+    Json = "application/json",
+    Png = "image/png",
+    #[other(prefix = "font/")]
+    Font,
+}
+
+#[test]
+fn looks_up_values_in_the_map() {
+    assert_eq!(MediaType::from("image/png"), MediaType::Png);
+    assert_eq!(MediaType::from("text/json".to_string()), MediaType::Json);
+    assert_eq!(
+        MediaType::from("font/woff2"),
+        MediaType::Font("font/woff2".to_string())
+    );
+    assert_eq!(
+        MediaType::from("text/css"),
+        MediaType::Other("text/css".to_string())
+    );
+    assert_eq!(String::from(MediaType::Html), "text/html");
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "regex")]
+
+use std::panic;
+
+use enum_other::other;
+
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum MediaType {
+    Json = "application/json",
+    #[other(pattern = r"^application/.+\+json$")]
+    JsonBased,
+    #[other(pattern = r"^image/(png|gif)$")]
+    Image,
+}
+
+/// Unknown classes are only found by the `regex` crate.
+#[other(String)]
+#[derive(Debug, PartialEq, Eq)]
+enum Broken {
+    #[other(pattern = r"^\p{Unknown}$")]
+    Unknown,
+}
+
+#[test]
+fn matches_patterns() {
+    assert_eq!(MediaType::from("application/json"), MediaType::Json);
+    assert_eq!(
+        MediaType::from("application/ld+json"),
+        MediaType::JsonBased("application/ld+json".to_string())
+    );
+    assert_eq!(
+        MediaType::from("image/gif"),
+        MediaType::Image("image/gif".to_string())
+    );
+    assert_eq!(
+        MediaType::from("image/jpeg"),
+        MediaType::Other("image/jpeg".to_string())
+    );
+    assert_eq!(
+        String::from(MediaType::Image("image/png".to_string())),
+        "image/png"
+    );
+}
+
+#[test]
+fn invalid_patterns_panic_on_first_use() {
+    let error = panic::catch_unwind(|| Broken::from("x")).unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("invalid pattern for `Broken::Unknown`: "));
+}
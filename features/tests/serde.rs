@@ -0,0 +1,38 @@
+#![cfg(feature = "serde")]
+
+use enum_other::other;
+
+#[other(String, serde)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    Post = "POST",
+}
+
+#[other(String, serde, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum KnownMethod {
+    Get = "GET",
+}
+
+#[test]
+fn deserializes_known_and_other_strings() {
+    assert_eq!(
+        serde_json::from_str::<Method>(r#""GET""#).unwrap(),
+        Method::Get
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<Method>>(r#"["POST", "PUT"]"#).unwrap(),
+        [Method::Post, Method::Other("PUT".to_string())]
+    );
+    assert!(serde_json::from_str::<Method>("1").is_err());
+}
+
+#[test]
+fn strict_enums_reject_other_strings() {
+    assert_eq!(
+        serde_json::from_str::<KnownMethod>(r#""GET""#).unwrap(),
+        KnownMethod::Get
+    );
+    assert!(serde_json::from_str::<KnownMethod>(r#""PUT""#).is_err());
+}
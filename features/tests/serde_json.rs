@@ -0,0 +1,61 @@
+#![cfg(feature = "serde_json")]
+
+use enum_other::other;
+use serde_json::{json, Value};
+
+#[other(i16, serde_json)]
+#[derive(Debug, PartialEq, Eq)]
+enum Offset {
+    Back = -1,
+    Forward = 1,
+}
+
+#[other(u8, serde_json, strict)]
+#[derive(Debug, PartialEq, Eq)]
+enum Opcode {
+    Query = 0,
+}
+
+#[other(String, serde_json, from_ref)]
+#[derive(Debug, PartialEq, Eq)]
+enum Method {
+    Get = "GET",
+    #[other(prefix = "X-")]
+    Extension,
+}
+
+#[test]
+fn integers_convert_to_numbers() {
+    assert_eq!(Offset::Back.to_json_value(), json!(-1));
+    assert_eq!(Offset::Other(300).to_json_value(), json!(300));
+    assert_eq!(Offset::from_json_value(&json!(1)), Some(Offset::Forward));
+    assert_eq!(
+        Offset::from_json_value(&json!(300)),
+        Some(Offset::Other(300))
+    );
+    assert_eq!(Offset::from_json_value(&json!(40000)), None);
+    assert_eq!(Offset::from_json_value(&json!("1")), None);
+
+    assert_eq!(Opcode::Query.to_json_value(), json!(0));
+    assert_eq!(Opcode::from_json_value(&json!(0)), Some(Opcode::Query));
+    assert_eq!(Opcode::from_json_value(&json!(5)), None);
+}
+
+#[test]
+fn strings_convert_to_strings() {
+    assert_eq!(Method::Get.to_json_value(), json!("GET"));
+    assert_eq!(
+        Method::Extension("X-PURGE".to_string()).to_json_value(),
+        json!("X-PURGE")
+    );
+    assert_eq!(
+        Method::Other("PUT".to_string()).to_json_value(),
+        json!("PUT")
+    );
+    assert_eq!(Method::from_json_value(&json!("GET")), Some(Method::Get));
+    assert_eq!(
+        Method::from_json_value(&json!("PUT")),
+        Some(Method::Other("PUT".to_string()))
+    );
+    assert_eq!(Method::from_json_value(&Value::Null), None);
+}
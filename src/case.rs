@@ -0,0 +1,175 @@
+//! Case styles for string discriminants derived from variant identifiers.
+
+use syn::{Error, LitStr, Result};
+
+/// A case style given to `rename_all`.
+#[derive(Clone, Copy)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    const ALL: &'static [(&'static str, Self)] = &[
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("PascalCase", Self::Pascal),
+        ("camelCase", Self::Camel),
+        ("snake_case", Self::Snake),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("kebab-case", Self::Kebab),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+    ];
+
+    pub fn from_lit(lit: &LitStr) -> Result<Self> {
+        let value = lit.value();
+        Self::ALL
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|&(_, rule)| rule)
+            .ok_or_else(|| {
+                let names = Self::ALL
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect::<Vec<_>>();
+                Error::new(
+                    lit.span(),
+                    format!(
+                        "unknown case style `{}`, expected one of {}",
+                        value,
+                        names.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Applies the style to a `PascalCase` variant identifier.
+    pub fn apply(self, ident: &str) -> String {
+        match self {
+            Self::Lower => ident.to_ascii_lowercase(),
+            Self::Upper => ident.to_ascii_uppercase(),
+            Self::Pascal => ident.to_owned(),
+            Self::Camel => {
+                let mut chars = ident.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_ascii_lowercase().to_string() + chars.as_str()
+                })
+            }
+            Self::Snake => separate(ident, '_'),
+            Self::ScreamingSnake => separate(ident, '_').to_ascii_uppercase(),
+            Self::Kebab => separate(ident, '-'),
+            Self::ScreamingKebab => separate(ident, '-').to_ascii_uppercase(),
+        }
+    }
+
+    /// Applies the style to `prefix` followed by a `PascalCase` variant
+    /// identifier. The prefix is split into words at `_`, `-` and changes of
+    /// case, while other characters such as `.` are kept as they are.
+    pub fn apply_prefixed(self, prefix: &str, ident: &str) -> String {
+        let (separator, upper) = match self {
+            Self::Lower => return (prefix.to_owned() + ident).to_ascii_lowercase(),
+            Self::Upper => return (prefix.to_owned() + ident).to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => (None, false),
+            Self::Snake => (Some('_'), false),
+            Self::ScreamingSnake => (Some('_'), true),
+            Self::Kebab => (Some('-'), false),
+            Self::ScreamingKebab => (Some('-'), true),
+        };
+
+        let mut joined = String::with_capacity(prefix.len() + ident.len() + 4);
+        let mut after_word = false;
+        for (i, word) in words(prefix).into_iter().chain(words(ident)).enumerate() {
+            let word = match word {
+                Word::Letters(word) => word,
+                Word::Other(other) => {
+                    joined.push_str(other);
+                    after_word = false;
+                    continue;
+                }
+            };
+            if after_word {
+                joined.extend(separator);
+            }
+            after_word = true;
+            let mut chars = word.chars();
+            let first = chars.next().unwrap();
+            match self {
+                Self::Camel if i == 0 => joined.push(first.to_ascii_lowercase()),
+                Self::Pascal | Self::Camel => joined.push(first.to_ascii_uppercase()),
+                _ if upper => joined.push(first.to_ascii_uppercase()),
+                _ => joined.push(first.to_ascii_lowercase()),
+            }
+            for c in chars {
+                joined.push(if upper {
+                    c.to_ascii_uppercase()
+                } else {
+                    c.to_ascii_lowercase()
+                });
+            }
+        }
+        joined
+    }
+}
+
+/// A piece of a name split by [`words`].
+enum Word<'a> {
+    Letters(&'a str),
+    /// A character kept between words as it is.
+    Other(&'a str),
+}
+
+/// Splits `name` into words at `_` and `-`, before an uppercase letter
+/// following a lowercase one or a digit, and before the last letter of a run
+/// of uppercase ones followed by a lowercase one, as in `HTTPMethod`.
+fn words(name: &str) -> Vec<Word<'_>> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let chars = name.char_indices().collect::<Vec<_>>();
+    for (n, &(i, c)) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if start < i {
+                words.push(Word::Letters(&name[start..i]));
+            }
+            if c != '_' && c != '-' {
+                words.push(Word::Other(&name[i..i + c.len_utf8()]));
+            }
+            start = i + c.len_utf8();
+            continue;
+        }
+        let previous = n.checked_sub(1).map(|n| chars[n].1);
+        let next = chars.get(n + 1).map(|&(_, c)| c);
+        let boundary = c.is_uppercase()
+            && previous.is_some_and(|previous| {
+                previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || previous.is_uppercase() && next.is_some_and(char::is_lowercase)
+            });
+        if boundary && start < i {
+            words.push(Word::Letters(&name[start..i]));
+            start = i;
+        }
+    }
+    if start < name.len() {
+        words.push(Word::Letters(&name[start..]));
+    }
+    words
+}
+
+/// Lowercases `ident`, placing `separator` before every uppercase letter but
+/// the first.
+fn separate(ident: &str, separator: char) -> String {
+    let mut separated = String::with_capacity(ident.len() + 4);
+    for (i, c) in ident.char_indices() {
+        if i > 0 && c.is_uppercase() {
+            separated.push(separator);
+        }
+        separated.push(c.to_ascii_lowercase());
+    }
+    separated
+}
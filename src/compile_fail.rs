@@ -0,0 +1,929 @@
+//! Inputs that the macro must reject with a compile error. These only check
+//! that compilation fails, while the cases in `tests/compile_fail` also check
+//! the errors reported.
+//!
+//! Negative discriminants are not valid for unsigned types:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Temperature {
+//!     Freezing = -1,
+//!     Cold,
+//! }
+//! ```
+//!
+//! Every invalid discriminant is reported, not only the first one:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! enum Offset {
+//!     Back = -1,
+//!     Overflowing = 99999999999999999999999999999999,
+//! }
+//! ```
+//!
+//! Two variants cannot decode from the same value:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Opcode {
+//!     Query = 0,
+//!     Status = 2,
+//!     #[other(alias = 2)]
+//!     Notify = 4,
+//! }
+//! ```
+//!
+//! Unknown variant options are rejected:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Opcode {
+//!     #[other(aliases = 1)]
+//!     Query = 0,
+//! }
+//! ```
+//!
+//! Ranges cannot overlap discriminants or other ranges:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Opcode {
+//!     Query = 0,
+//!     Reserved = 0..=15,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Opcode {
+//!     Reserved = 0..=15,
+//!     PrivateUse = 15..,
+//! }
+//! ```
+//!
+//! Bitmask discriminants must be powers of two:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, bitmask)]
+//! enum Flags {
+//!     Read = 1,
+//!     Write = 2,
+//!     ReadWrite = 3,
+//! }
+//! ```
+//!
+//! Attributes given through `other_impl_attr` apply to the generated impls:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! #[other_impl_attr(cfg(any()))]
+//! enum Opcode {
+//!     Query = 0,
+//! }
+//!
+//! let _ = Opcode::from(0);
+//! ```
+//!
+//! Discriminants cannot be inferred after one that cannot be evaluated:
+//!
+//! ```compile_fail
+//! const BASE: u16 = 0x100;
+//!
+//! #[enum_other::other(u16)]
+//! enum Register {
+//!     Extended = BASE + 1,
+//!     Next,
+//! }
+//! ```
+//!
+//! Variants with fields must be skipped:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Frame {
+//!     Data = 0,
+//!     Unparsed(Vec<u8>),
+//! }
+//! ```
+//!
+//! Skipped variants without a value cannot be encoded:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Frame {
+//!     Data = 0,
+//!     #[other(skip)]
+//!     Unparsed(Vec<u8>),
+//! }
+//!
+//! let _ = u8::from(Frame::Data);
+//! ```
+//!
+//! Strict enums cannot name a fallback variant:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, Unknown, strict)]
+//! enum Version {
+//!     V1 = 1,
+//! }
+//! ```
+//!
+//! Nor can they be decoded infallibly:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, strict)]
+//! enum Version {
+//!     V1 = 1,
+//! }
+//!
+//! let _ = Version::from(1);
+//! ```
+//!
+//! Bitmask enums cannot be strict:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, bitmask, strict)]
+//! enum Access {
+//!     Read = 1,
+//! }
+//! ```
+//!
+//! Renamed variants cannot collide with another variant's string:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String)]
+//! enum Header {
+//!     Host = "Host",
+//!     #[other(rename = "Host")]
+//!     ForwardedHost,
+//! }
+//! ```
+//!
+//! Only string enums can be renamed:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Version {
+//!     #[other(rename = "1")]
+//!     V1 = 1,
+//! }
+//! ```
+//!
+//! Attributes for the "other" variant also remove the code using it:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! #[other_attr(cfg(any()))]
+//! enum Opcode {
+//!     Text = 1,
+//! }
+//!
+//! let _ = Opcode::from(1);
+//! ```
+//!
+//! Strict enums have no "other" variant to add attributes to:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, strict)]
+//! #[other_attr(doc = "Unused.")]
+//! enum Opcode {
+//!     Text = 1,
+//! }
+//! ```
+//!
+//! Identifiers used as strings must not collide with other discriminants:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String)]
+//! enum Encoding {
+//!     Gzip = "Identity",
+//!     Identity,
+//! }
+//! ```
+//!
+//! Case styles must be one of the supported ones:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String, rename_all = "Title Case")]
+//! enum Header {
+//!     ContentType,
+//! }
+//! ```
+//!
+//! Labels require `display`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! enum RecordKind {
+//!     #[other(display = "Mail exchange record")]
+//!     Mx = 15,
+//! }
+//! ```
+//!
+//! Nor after an associated constant:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Level {
+//!     Lowest = u8::MIN,
+//!     Low,
+//! }
+//! ```
+//!
+//! Case insensitive strings must differ by more than case:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String, case_insensitive)]
+//! enum HeaderName {
+//!     Etag = "ETag",
+//!     Etag2 = "etag",
+//! }
+//! ```
+//!
+//! Only string enums can be deserialized:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, serde)]
+//! enum Method {
+//!     Get = 1,
+//! }
+//! ```
+//!
+//! Normalizing functions must return `Cow<str>`:
+//!
+//! ```compile_fail
+//! fn trim(value: &str) -> String {
+//!     value.trim().to_string()
+//! }
+//!
+//! #[enum_other::other(String, normalize = trim)]
+//! enum Command {
+//!     Quit = "QUIT",
+//! }
+//! ```
+//!
+//! The fallback variant cannot be made non-exhaustive:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! #[other_attr(non_exhaustive)]
+//! pub enum ContentType {
+//!     Alert = 21,
+//! }
+//! ```
+//!
+//! Prefixes must be distinct:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String)]
+//! enum HeaderName {
+//!     #[other(prefix = "X-")]
+//!     Extension,
+//!     #[other(prefix = "X-")]
+//!     Experimental,
+//! }
+//! ```
+//!
+//! Patterns must be valid regular expressions:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String)]
+//! enum MediaType {
+//!     #[other(pattern = r"application/(.+\+json")]
+//!     JsonBased,
+//! }
+//! ```
+//!
+//! Only primitive integers get a representation:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String, repr)]
+//! enum Method {
+//!     Get = "GET",
+//! }
+//! ```
+//!
+//! Only string enums can be used as clap values:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, clap)]
+//! #[derive(Clone)]
+//! enum Level {
+//!     Low = 1,
+//! }
+//! ```
+//!
+//! Fallbacks need a variant holding the remaining values:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, fallbacks(Private = 0xff00..=0xffff))]
+//! enum RecordType {
+//!     A = 1,
+//! }
+//! ```
+//!
+//! Fallback ranges cannot overlap the discriminants:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, fallbacks(Low = 0..16, Unassigned = _))]
+//! enum RecordType {
+//!     A = 1,
+//! }
+//! ```
+//!
+//! Discriminants must be within the valid range:
+//!
+//! ```compile_fail
+//! #[enum_other::other(i8, valid = -8..8, invalid = Invalid)]
+//! enum Offset {
+//!     Stay = 0,
+//!     Jump = 8,
+//! }
+//! ```
+//!
+//! The enum held by the fallback variant must convert to and from the data
+//! type:
+//!
+//! ```compile_fail
+//! pub enum VendorOption {
+//!     Trace,
+//! }
+//!
+//! #[enum_other::other(u16, fallback_into = VendorOption)]
+//! pub enum EdnsOption {
+//!     Nsid = 3,
+//! }
+//! ```
+//!
+//! The fields of the other variant can only be named once:
+//!
+//! ```compile_fail
+//! #[enum_other::other((u8, u8), Unknown { major, minor }, named_other = [major, minor])]
+//! enum Version {
+//!     First = (1, 0),
+//! }
+//! ```
+//!
+//! Only tuples of a single element type can be iterated over:
+//!
+//! ```compile_fail
+//! #[enum_other::other((u8, u16), into_iter)]
+//! enum Version {
+//!     First = (1, 0),
+//! }
+//! ```
+//!
+//! Options must be known, and the other variant named once:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, display, ord)]
+//! enum Radix {
+//!     Binary = 2,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, Unknown, ident = Unrecognized)]
+//! enum Radix {
+//!     Binary = 2,
+//! }
+//! ```
+//!
+//! Only integer enums get a known companion:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String, known = MethodKnown)]
+//! enum Method {
+//!     Get = "GET",
+//! }
+//! ```
+//!
+//! Enums left as written by `wrapper` keep their discriminants, so they must
+//! be fieldless, over an integer type, and take no other options:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, wrapper = MaybeRadix)]
+//! enum Radix {
+//!     Binary = 2,
+//!     Custom(u16),
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other(String, wrapper = MaybeMethod)]
+//! enum Method {
+//!     Get = "GET",
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, display, wrapper = MaybeRadix)]
+//! enum Radix {
+//!     Binary = 2,
+//! }
+//! ```
+//!
+//! `None` can only go to a single sentinel variant, which holds no data:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Compression {
+//!     #[other(sentinel)]
+//!     None = 0,
+//!     #[other(sentinel)]
+//!     Missing = 1,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Compression {
+//!     #[other(sentinel)]
+//!     Private = 200..=255,
+//! }
+//! ```
+//!
+//! Discriminants kept by a `#[repr(...)]` must fit it, and leave the tag after
+//! them free for variants without one:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! #[repr(u8)]
+//! enum Port {
+//!     Https = 443,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, repr)]
+//! enum Kind {
+//!     Request = 1,
+//!     Private = 10..20,
+//!     Response = 2,
+//! }
+//! ```
+//!
+//! Traits given with `impl_trait` need a path and a method, and can only
+//! decode enums that are not `strict`:
+//!
+//! ```compile_fail
+//! pub trait WireCodec {
+//!     fn encode(&self) -> u16;
+//! }
+//!
+//! #[enum_other::other(u16, impl_trait(encode = encode))]
+//! enum Radix {
+//!     Binary = 2,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! pub trait WireCodec {
+//!     fn decode(value: u16) -> Self;
+//! }
+//!
+//! #[enum_other::other(u16, strict, impl_trait(path = WireCodec, decode = decode))]
+//! enum Radix {
+//!     Binary = 2,
+//! }
+//! ```
+//!
+//! Byte string enums have no discriminants to infer:
+//!
+//! ```compile_fail
+//! #[enum_other::other(Vec<u8>)]
+//! enum TlvType {
+//!     Hostname = b"\x01",
+//!     Port,
+//! }
+//! ```
+//!
+//! The data type must be concrete:
+//!
+//! ```compile_fail
+//! #[enum_other::other(impl Into<u16>)]
+//! enum Radix {
+//!     Binary = 2,
+//! }
+//! ```
+//!
+//! Inherent items restricted with `vis` cannot be used outside of it:
+//!
+//! ```compile_fail
+//! mod protocol {
+//!     #[enum_other::other(u16, vis = pub(self))]
+//!     pub enum RecordType {
+//!         A = 1,
+//!     }
+//! }
+//!
+//! protocol::RecordType::from_name("A");
+//! ```
+//!
+//! The arity of an alias must match the tuple it names, and is not needed for
+//! tuples written out:
+//!
+//! ```compile_fail
+//! type Rgb = (u8, u8);
+//!
+//! #[enum_other::other(Rgb, arity = 3)]
+//! enum Color {
+//!     Black = (0, 0),
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[enum_other::other((u8, u8, u8), arity = 3)]
+//! enum Color {
+//!     Black = (0, 0, 0),
+//! }
+//! ```
+//!
+//! `deny_unknown` guards the fallback variant that `strict` leaves out:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, strict, deny_unknown)]
+//! enum Opcode {
+//!     Nop = 0x00,
+//! }
+//! ```
+//!
+//! The type given with `store` must convert from the data type:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, store = u8)]
+//! enum Port {
+//!     Ssh = 22,
+//! }
+//! ```
+//!
+//! `boxed` sets the type held by the fallback variant, like `store`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(String, boxed, store = Box<str>)]
+//! enum Scheme {
+//!     Http = "http",
+//! }
+//! ```
+//!
+//! Skipped variants are never decoded, so they have no `since` version:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! enum Command {
+//!     Hello = 1,
+//!     #[other(skip, since = 2)]
+//!     Resume = 3,
+//! }
+//! ```
+//!
+//! `from_tuple` needs one type for each element of the data type:
+//!
+//! ```compile_fail
+//! #[enum_other::other((u8, u8), from_tuple = (u8,))]
+//! enum ProtocolVersion {
+//!     Http11 = (1, 1),
+//! }
+//! ```
+//!
+//! The function given with `on_unknown` must take a reference to the value:
+//!
+//! ```compile_fail
+//! fn record(_: u16) {}
+//!
+//! #[enum_other::other(u16, on_unknown = record)]
+//! enum RecordType {
+//!     A = 1,
+//! }
+//! ```
+//!
+//! Only one of the variants sharing a discriminant can be the primary:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! enum RecordType {
+//!     #[other(primary)]
+//!     SvcbHttps = 65,
+//!     #[other(primary)]
+//!     Https = 65,
+//! }
+//! ```
+//!
+//! `exhaustive` enums must decode every value to a variant:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, exhaustive)]
+//! enum Half {
+//!     Low = 0..128,
+//! }
+//! ```
+//!
+//! Discriminants must fit within the `mask`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, mask = 0xF0)]
+//! enum Packet {
+//!     Connect = 0x11,
+//! }
+//! ```
+//!
+//! Discriminants must fit in the data type:
+//!
+//! ```compile_fail
+//! #[enum_other::other(i8)]
+//! enum Level {
+//!     High = 200,
+//! }
+//! ```
+//!
+//! `default_other` cannot be combined with a `#[default]` variant:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, default_other)]
+//! #[derive(Default)]
+//! enum Opcode {
+//!     #[default]
+//!     Nop = 0,
+//! }
+//! ```
+//!
+//! Characters have no discriminants to infer either:
+//!
+//! ```compile_fail
+//! #[enum_other::other(char)]
+//! enum Flag {
+//!     Verbose = 'v',
+//!     Quiet,
+//! }
+//! ```
+//!
+//! Byte literals are only valid for `u8`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! enum Prefix {
+//!     Plus = b'+',
+//! }
+//! ```
+//!
+//! `other_newtype` only forwards to the single field of a struct:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! pub enum RecordType {
+//!     A = 1,
+//! }
+//!
+//! #[enum_other::other_newtype(u16)]
+//! pub struct Query(RecordType, u16);
+//! ```
+//!
+//! Only string enums can be collected with a `writer`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, writer = OpcodeWriter)]
+//! enum Opcode {
+//!     Nop = 0,
+//! }
+//! ```
+//!
+//! Byte strings must have the length of the array:
+//!
+//! ```compile_fail
+//! #[enum_other::other([u8; 4])]
+//! enum Codec {
+//!     Avc = b"avc",
+//! }
+//! ```
+//!
+//! Arrays must have the length of the data type:
+//!
+//! ```compile_fail
+//! #[enum_other::other([u16; 2])]
+//! enum Version {
+//!     V1 = [1, 0, 0],
+//! }
+//! ```
+//!
+//! Array discriminants cannot be inferred:
+//!
+//! ```compile_fail
+//! #[enum_other::other([u16; 2])]
+//! enum Version {
+//!     V1 = [1, 0],
+//!     V2,
+//! }
+//! ```
+//!
+//! Byte strings and arrays of the same bytes are the same discriminant:
+//!
+//! ```compile_fail
+//! #[enum_other::other([u8; 2])]
+//! enum ExtensionType {
+//!     ServerName = [0x00, 0x00],
+//!     MaxFragmentLength = b"\0\0",
+//! }
+//! ```
+//!
+//! Array elements must fit in the element type:
+//!
+//! ```compile_fail
+//! #[enum_other::other([u8; 2])]
+//! enum ExtensionType {
+//!     ServerName = [0x00, 0x100],
+//! }
+//! ```
+//!
+//! Float discriminants cannot be inferred:
+//!
+//! ```compile_fail
+//! #[enum_other::other(f32)]
+//! enum Sample {
+//!     Zero = 0.0,
+//!     Half,
+//! }
+//! ```
+//!
+//! `assert_coverage!` only checks integer types:
+//!
+//! ```compile_fail
+//! #[enum_other::other(char)]
+//! enum Flag {
+//!     Verbose = 'v',
+//! }
+//!
+//! enum_other::assert_coverage!(Flag, char);
+//! ```
+//!
+//! Discriminants are only counted up to `i128::MAX`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u128)]
+//! enum Scope {
+//!     Max = 170141183460469231731687303715884105727,
+//!     Above,
+//! }
+//! ```
+//!
+//! Zero is not a value of `NonZero` types:
+//!
+//! ```compile_fail
+//! #[enum_other::other(std::num::NonZeroU16)]
+//! enum Port {
+//!     Unset = 0,
+//! }
+//! ```
+//!
+//! Nor can they be matched against ranges:
+//!
+//! ```compile_fail
+//! #[enum_other::other(std::num::NonZeroU16)]
+//! enum Port {
+//!     System = 1..=1023,
+//! }
+//! ```
+//!
+//! Discriminants of wrapped integers must fit in the integer:
+//!
+//! ```compile_fail
+//! #[enum_other::other(std::num::Wrapping<u8>)]
+//! enum Checksum {
+//!     Large = 256,
+//! }
+//! ```
+//!
+//! `inner` only names integer types:
+//!
+//! ```compile_fail
+//! pub struct Name(pub String);
+//!
+//! #[enum_other::other(Name, inner = String)]
+//! enum Known {
+//!     Root = "root",
+//! }
+//! ```
+//!
+//! Only integer and string enums have JSON values:
+//!
+//! ```compile_fail
+//! #[enum_other::other(f32, serde_json)]
+//! enum Ratio {
+//!     Half = 0.5,
+//! }
+//! ```
+//!
+//! Only string enums can be looked up with phf:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, phf)]
+//! enum Level {
+//!     Low = 1,
+//! }
+//! ```
+//!
+//! Named fields of the other variant must match the elements of the type:
+//!
+//! ```compile_fail
+//! #[enum_other::other((u8, u8), Unknown { major })]
+//! enum Version {
+//!     First = (1, 0),
+//! }
+//! ```
+//!
+//! Only `attrs` takes a parenthesized list:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, other_attrs(deprecated))]
+//! enum Opcode {
+//!     Nop = 0,
+//! }
+//! ```
+//!
+//! Indexable enums cannot skip an index:
+//!
+//! ```compile_fail
+//! #[enum_other::other(usize, indexable)]
+//! enum Register {
+//!     Rax = 0,
+//!     Rcx = 2,
+//! }
+//! ```
+//!
+//! Implicit discriminants cannot overflow the type without `wrapping`:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! enum Sequence {
+//!     Last = 255,
+//!     First,
+//! }
+//! ```
+//!
+//! A declared fallback variant must hold the data type:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! enum Port {
+//!     Http = 80,
+//!     Other(u32),
+//! }
+//! ```
+//!
+//! A declared fallback variant cannot also be given a position:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, position = first)]
+//! enum Port {
+//!     Http = 80,
+//!     Other(u16),
+//! }
+//! ```
+//!
+//! Nor can the position be past the end of the variants:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16, position = 3)]
+//! enum Port {
+//!     Http = 80,
+//!     Https = 443,
+//! }
+//! ```
+//!
+//! Only one of `from_only`, `into_only` and `no_impls` can be given:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8, from_only, into_only)]
+//! enum Shape {
+//!     Circle = 1,
+//! }
+//! ```
+//!
+//! Deriving requires the fallback variant to be declared:
+//!
+//! ```compile_fail
+//! #[derive(enum_other::EnumOther)]
+//! #[other(type = u8)]
+//! enum Level {
+//!     Low = 1,
+//! }
+//! ```
+//!
+//! Variants cannot have their own visibility:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u8)]
+//! pub enum Level {
+//!     pub(crate) Low = 1,
+//! }
+//! ```
+//!
+//! The macro cannot be applied twice:
+//!
+//! ```compile_fail
+//! #[enum_other::other(u16)]
+//! #[enum_other::other(String)]
+//! enum RecordType {
+//!     A = 1,
+//! }
+//! ```
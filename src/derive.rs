@@ -0,0 +1,149 @@
+//! The `EnumOther` derive, which generates the same items as the attribute
+//! for an enum that already declares its "other" variant.
+
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{ext::IdentExt, parse::ParseStream, Error, Ident, ItemEnum, Result, Token, Type};
+
+use crate::{Args, Errors};
+
+pub(crate) fn expand(mut item: ItemEnum) -> Result<TokenStream2> {
+    let mut args = None;
+    let mut errors = Errors::default();
+    item.attrs.retain(|attr| {
+        if !attr.path.is_ident("other") {
+            return true;
+        }
+
+        let parsed = if args.is_some() {
+            Err(Error::new_spanned(
+                attr,
+                "duplicate `#[other(...)]` attribute",
+            ))
+        } else {
+            attr.parse_args_with(parse_args)
+        };
+        match parsed {
+            Ok(tokens) => args = Some(tokens),
+            Err(error) => errors.push(error),
+        }
+        false
+    });
+    errors.finish()?;
+
+    let args = args.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "expected an `#[other(type = ...)]` attribute giving the data type",
+        )
+    })?;
+    let args: Args = syn::parse2(args)?;
+
+    if let Some((position, _)) = &args.position {
+        errors.push(unavailable(position.span(), "position"));
+    }
+    if let Some(fallback) = args.fallbacks.first() {
+        errors.push(unavailable(fallback.ident.span(), "fallbacks"));
+    }
+    if let Some((invalid, _)) = &args.invalid {
+        errors.push(unavailable(invalid.span(), "invalid"));
+    }
+    if args.deprecate_other || args.hide_other {
+        errors.push(Error::new(
+            Span::call_site(),
+            "`deprecate_other` and `hide_other` cannot be used when deriving, add the attributes to the variant instead",
+        ));
+    }
+    if args.repr {
+        errors.push(unavailable(Span::call_site(), "repr"));
+    }
+    if args.non_exhaustive {
+        errors.push(unavailable(Span::call_site(), "non_exhaustive"));
+    }
+    if args.doc_aliases {
+        errors.push(unavailable(Span::call_site(), "doc_aliases"));
+    }
+    if let Some(attr) = args.other_attrs.first() {
+        errors.push(Error::new_spanned(
+            attr,
+            "`attrs(...)` cannot be used when deriving, add the attributes to the variant instead",
+        ));
+    }
+    if !args.strict
+        && !item
+            .variants
+            .iter()
+            .any(|variant| variant.ident == args.other_ident)
+    {
+        let data_type = &args.data_type;
+        errors.push(Error::new_spanned(
+            &item.ident,
+            format!(
+                "deriving cannot add the fallback variant, so `{}` holding `{}` must be declared in the enum",
+                args.other_ident,
+                quote! { #data_type },
+            ),
+        ));
+    }
+
+    errors.finish()?;
+
+    crate::expand(args, &mut item).map(|impls| quote! { #impls })
+}
+
+/// Turns the `#[other(...)]` of the derive into the arguments of the
+/// attribute, which take the data type and identifier by position.
+fn parse_args(input: ParseStream) -> Result<TokenStream2> {
+    let mut data_type: Option<Type> = None;
+    let mut other_ident: Option<Ident> = None;
+    let mut rest = TokenStream2::new();
+
+    while !input.is_empty() {
+        let key = input.call(Ident::parse_any)?;
+        match key.to_string().as_str() {
+            "type" | "ident" if input.peek(Token![=]) => {
+                input.parse::<Token![=]>()?;
+                let duplicate = if key == "type" {
+                    data_type.replace(input.parse()?).is_some()
+                } else {
+                    other_ident.replace(input.parse()?).is_some()
+                };
+                if duplicate {
+                    return Err(Error::new(
+                        key.span(),
+                        format!("duplicate option `{}`", key),
+                    ));
+                }
+            }
+            _ => {
+                rest.extend(quote! { , #key });
+                while !input.is_empty() && !input.peek(Token![,]) {
+                    rest.extend([input.parse::<TokenTree>()?]);
+                }
+            }
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    let data_type = data_type.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "expected `type = ...` giving the data type",
+        )
+    })?;
+    let other_ident = other_ident.map(|ident| quote! { , #ident });
+    Ok(quote! { #data_type #other_ident #rest })
+}
+
+fn unavailable(span: Span, option: &str) -> Error {
+    Error::new(
+        span,
+        format!(
+            "`{}` cannot be used when deriving, as it changes the enum",
+            option
+        ),
+    )
+}
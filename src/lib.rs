@@ -56,6 +56,12 @@
 //!
 //! impl From<u16> for DnsRecordType {
 //!     fn from(value: u16) -> Self {
+//!         #[cold]
+//!         #[inline(never)]
+//!         fn cold_other(value: u16) -> DnsRecordType {
+//!             DnsRecordType::Other(value)
+//!         }
+//!
 //!         match value {
 //!             1 => Self::A,
 //!             2 => Self::Ns,
@@ -66,14 +72,70 @@
 //!             16 => Self::Txt,
 //!             28 => Self::Aaaa,
 //!             33 => Self::Srv,
-//!             _ => Self::Other(value),
+//!             _ => cold_other(value),
 //!         }
 //!     }
 //! }
 //! ```
 //!
-//! As it generates match statements, the discriminants must both be valid
-//! expressions and patterns.
+//! As it generates match statements, discriminants that are valid patterns are
+//! matched directly, while other constant expressions are compared in match
+//! guards instead.
+//!
+//! Unknown values are constructed in a separate `#[cold]` function, keeping
+//! the known variants on the fast path.
+//!
+//! For primitive types like `u16` that are `Copy`, the value can also be taken
+//! from a reference to the enum with `From<&DnsRecordType>`. Other types get
+//! this conversion by cloning the enum when passing `from_ref`, and `String`
+//! enums borrow their strings as `&str`.
+//!
+//! Neither `From` nor `Arc` and `Rc` are defined in the crate using the
+//! macro, so it cannot implement `From<u16> for Arc<DnsRecordType>`. Values
+//! are instead wrapped through the enum, using `From<T> for Arc<T>`:
+//!
+//! ```
+//! use std::{rc::Rc, sync::Arc};
+//!
+//! #[enum_other::other(u16)]
+//! #[derive(Debug, PartialEq, Eq)]
+//! enum DnsRecordType {
+//!     A = 1,
+//!     Ns = 2,
+//! }
+//!
+//! let shared: Arc<DnsRecordType> = DnsRecordType::from(2).into();
+//! assert_eq!(*shared, DnsRecordType::Ns);
+//! let local = Rc::new(DnsRecordType::from(7));
+//! assert_eq!(*local, DnsRecordType::Other(7));
+//! ```
+//!
+//! The data type must be a concrete type. Conversions generic over it, such as
+//! `impl<T: Into<u16>> From<T> for DnsRecordType`, would overlap with the
+//! `From<T> for T` of the standard library, and `impl<T: From<u16>>
+//! From<DnsRecordType> for T` implements a foreign trait for any type, which
+//! the orphan rules forbid. Code generic over the width of the wire integer
+//! can go through the data type instead:
+//!
+//! ```
+//! #[enum_other::other(u16)]
+//! #[derive(Debug, PartialEq, Eq)]
+//! enum DnsRecordType {
+//!     A = 1,
+//!     Ns = 2,
+//! }
+//!
+//! fn decode<T: Into<u16>>(value: T) -> DnsRecordType {
+//!     DnsRecordType::from(value.into())
+//! }
+//!
+//! fn encode<T: From<u16>>(value: DnsRecordType) -> T {
+//!     T::from(u16::from(value))
+//! }
+//!
+//! assert_eq!(decode(2u8), DnsRecordType::Ns);
+//! assert_eq!(encode::<u32>(DnsRecordType::A), 1);
+//! ```
 //!
 //! There exist special rules for tuple types, which have their contents
 //! flattened in the "other" value.
@@ -81,55 +143,1855 @@
 //! When the discriminants are string literals, the macro will automatically
 //! add calls to to_string and as_str where neccesary to allow for string types
 //! to be used.
+//! Enums converted to and from `String` can also be converted from `&str`,
+//! only allocating for strings that are kept.
+//!
+//! Enums that declare the "other" variant themselves can instead use
+//! `#[derive(EnumOther)]`, which generates the same implementations without
+//! changing the enum.
+//!
+//! Tests can check that values are converted back unchanged with
+//! `assert_round_trip!`, and structs wrapping an enum can forward its
+//! conversions with `#[other_newtype(...)]`.
+
+mod case;
+#[cfg(doctest)]
+mod compile_fail;
+mod derive;
+mod mapping;
+mod newtype;
+mod pattern;
+mod round_trip;
+mod variant;
+mod wrapper;
 
-use proc_macro::{Span, TokenStream};
-use quote::{format_ident, quote};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    iter, mem,
+};
+
+use case::RenameRule;
+use mapping::ExtraMapping;
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    parse::{Parse, ParseStream, Result},
-    parse_macro_input, parse_quote,
+    braced, bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream, Parser, Result},
+    parse_macro_input, parse_quote, parse_quote_spanned,
     punctuated::Punctuated,
-    Expr, ExprLit, ExprUnary, Ident, ItemEnum, Lit, LitInt, Token, Type, TypeTuple, UnOp,
+    spanned::Spanned,
+    token, Attribute, BinOp, Error, Expr, ExprArray, ExprBinary, ExprGroup, ExprLit, ExprParen,
+    ExprRange, ExprTuple, ExprUnary, Fields, File, GenericArgument, Generics, Ident, Index, Item,
+    ItemEnum, ItemStruct, Lit, LitInt, LitStr, Meta, MetaNameValue, NestedMeta, Path,
+    PathArguments, RangeLimits, Token, Type, TypePath, TypeTuple, UnOp, Variant, Visibility,
+    WhereClause,
 };
+use variant::VariantArgs;
+
+/// Collects errors so that every problem with the input can be reported in a
+/// single compilation instead of stopping at the first one.
+#[derive(Default)]
+struct Errors(Option<Error>);
+
+impl Errors {
+    fn push(&mut self, error: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        match self.0.take() {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Where the "other" variant is inserted among the declared variants.
+enum Position {
+    First,
+    Last,
+    Index(LitInt),
+}
+
+impl Parse for Position {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitInt) {
+            return input.parse().map(Self::Index);
+        }
+
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            _ => Err(Error::new(
+                ident.span(),
+                "expected `first`, `last` or the index of the variant",
+            )),
+        }
+    }
+}
+
+/// A trait implemented through the conversions, with the names of its methods
+/// for each direction.
+struct ImplTrait {
+    path: Path,
+    encode: Option<Ident>,
+    decode: Option<Ident>,
+}
 
 struct Args {
     data_type: Type,
     other_ident: Ident,
+    other_field_names: Option<Punctuated<Ident, Token![,]>>,
+    other_attrs: Vec<Attribute>,
+    position: Option<(Ident, Position)>,
+    fallbacks: Vec<Variant>,
+    invalid: Option<(Ident, ExprRange)>,
+    bitmask: bool,
+    /// Bits of the value compared against the discriminants, leaving out
+    /// flags packed next to them.
+    mask: Option<Expr>,
+    from_name_ci: bool,
+    case_insensitive: bool,
+    display: bool,
+    serde: bool,
+    /// Whether the enum gets `to_json_value` and `from_json_value`.
+    serde_json: bool,
+    clap: bool,
+    phf: bool,
+    repr: bool,
+    non_exhaustive: bool,
+    indexable: bool,
+    into_iter: bool,
+    /// Whether the enum can be collected from the elements of its tuple.
+    from_iter: bool,
+    doc_table: bool,
+    /// Whether variants get doc aliases for their discriminants.
+    doc_aliases: bool,
+    error: bool,
+    deny_unknown: bool,
+    /// Whether the enum defaults to the "other" variant holding the default
+    /// value.
+    default_other: bool,
+    boxed: bool,
+    wrapping: bool,
+    from_ref: bool,
+    deprecate_other: bool,
+    hide_other: bool,
+    encode: bool,
+    decode: bool,
+    strict: bool,
+    /// Whether every value decodes to a known variant, so that decoding
+    /// cannot fail even though the enum is `strict`.
+    exhaustive: bool,
+    rename_all: Option<RenameRule>,
+    name_prefix: Option<LitStr>,
+    convert_with: Option<Path>,
+    fallback_into: Option<Type>,
+    /// Type held by the fallback variant in place of the data type.
+    store: Option<Type>,
+    /// Integer type wrapped by a tuple struct data type such as `Wrapping`.
+    inner: Option<Type>,
+    known_enum: Option<Ident>,
+    /// Companion type collecting text written with `fmt::Write`.
+    writer: Option<Ident>,
+    impl_trait: Option<ImplTrait>,
+    /// Number of elements of a tuple the data type is an alias of.
+    arity: Option<usize>,
+    /// Enums converting to each element of the data type, combined by a
+    /// further conversion.
+    from_tuple: Option<TypeTuple>,
+    /// Visibility of the generated inherent items and companion types.
+    vis: Option<Visibility>,
+    known_attrs: Vec<Attribute>,
+    /// Enum holding either the enum, left as written, or any other value.
+    wrapper: Option<Ident>,
+    wrapper_attrs: Vec<Attribute>,
+    normalize: Option<Path>,
+    /// Function called with every value decoded to the "other" variant.
+    on_unknown: Option<Path>,
+    store_normalized: bool,
+}
+
+/// Options the macro accepts after the data type.
+const OPTIONS: &[&str] = &[
+    "arity",
+    "attrs",
+    "bitmask",
+    "boxed",
+    "case_insensitive",
+    "clap",
+    "convert_with",
+    "default_other",
+    "deny_unknown",
+    "deprecate_other",
+    "display",
+    "doc_aliases",
+    "doc_table",
+    "error",
+    "exhaustive",
+    "fallback_into",
+    "fallbacks",
+    "from_name_ci",
+    "from_iter",
+    "from_only",
+    "from_ref",
+    "from_tuple",
+    "hide_other",
+    "ident",
+    "impl_trait",
+    "indexable",
+    "inner",
+    "into_iter",
+    "into_only",
+    "invalid",
+    "known",
+    "known_attrs",
+    "mask",
+    "named_other",
+    "no_impls",
+    "non_exhaustive",
+    "normalize",
+    "on_unknown",
+    "phf",
+    "position",
+    "prefix",
+    "rename_all",
+    "repr",
+    "serde",
+    "serde_json",
+    "store",
+    "store_normalized",
+    "strict",
+    "valid",
+    "vis",
+    "wrapper",
+    "wrapper_attrs",
+    "wrapping",
+    "writer",
+];
+
+fn unknown_option(option: &Ident) -> Error {
+    let expected = OPTIONS
+        .iter()
+        .map(|option| format!("`{}`", option))
+        .collect::<Vec<_>>();
+    Error::new(
+        option.span(),
+        format!(
+            "unknown option `{}`, expected one of {}",
+            option,
+            expected.join(", ")
+        ),
+    )
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream) -> Result<Self> {
         let data_type: Type = input.parse()?;
-        let comma: Option<Token![,]> = input.parse()?;
-        let other_ident: Option<Ident> = comma.and_then(|_| input.parse().ok());
+        if let Type::ImplTrait(_) | Type::TraitObject(_) = &data_type {
+            return Err(Error::new_spanned(
+                &data_type,
+                "the data type must be a concrete type, as conversions generic over it would overlap with `From<T> for T`",
+            ));
+        }
+        let mut other_ident: Option<Ident> = None;
+        let mut other_field_names = None;
+        let mut other_attrs = Vec::new();
+        let mut position = None;
+        let mut fallbacks = None;
+        let mut valid: Option<(Ident, ExprRange)> = None;
+        let mut invalid: Option<Ident> = None;
+        let mut bitmask = false;
+        let mut from_name_ci = false;
+        let mut case_insensitive = false;
+        let mut serde = false;
+        let mut serde_json = false;
+        let mut serde_json_span = None;
+        let mut clap = false;
+        let mut phf = false;
+        let mut repr = false;
+        let mut non_exhaustive = false;
+        let mut indexable = false;
+        let mut into_iter = false;
+        let mut from_iter = false;
+        let mut doc_table = false;
+        let mut doc_aliases = false;
+        let mut error = false;
+        let mut deny_unknown = false;
+        let mut default_other = false;
+        let mut exhaustive = false;
+        let mut boxed = false;
+        let mut wrapping = false;
+        let mut wrapping_span = None;
+        let mut from_ref = false;
+        let mut deprecate_other = false;
+        let mut hide_other = false;
+        let mut store_normalized = false;
+        let mut display = false;
+        let mut strict = None;
+        let mut only = None;
+        let mut rename_all = None;
+        let mut name_prefix = None;
+        let mut convert_with = None;
+        let mut fallback_into = None;
+        let mut store = None;
+        let mut inner = None;
+        let mut mask = None;
+        let mut from_tuple = None;
+        let mut known_enum: Option<Ident> = None;
+        let mut writer: Option<(Ident, Ident)> = None;
+        let mut known_attrs = None;
+        let mut impl_trait: Option<ImplTrait> = None;
+        let mut vis: Option<Visibility> = None;
+        let mut arity = None;
+        let mut wrapper: Option<Ident> = None;
+        let mut wrapper_attrs = None;
+        let mut normalize = None;
+        let mut on_unknown = None;
+        // Every option given, as most cannot be combined with `wrapper`.
+        let mut given = Vec::new();
+
+        let mut errors = Errors::default();
+        let mut first = true;
+        while !input.is_empty() {
+            if let Err(error) = input.parse::<Token![,]>() {
+                errors.push(error);
+                break;
+            }
+            if input.is_empty() {
+                break;
+            }
+
+            let mut parse_option = || -> Result<()> {
+                let option: Ident = input.parse()?;
+                given.push(option.clone());
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    match option.to_string().as_str() {
+                        "rename_all" => {
+                            let lit: LitStr = input.parse()?;
+                            if !is_string_type(&data_type) && !is_cow_str_type(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`rename_all` can only be used on enums converted to and from `String` or `Cow<str>`",
+                                ));
+                            }
+                            if rename_all.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            rename_all = Some(RenameRule::from_lit(&lit)?);
+                        }
+                        "prefix" => {
+                            let lit: LitStr = input.parse()?;
+                            if !is_string_type(&data_type) && !is_cow_str_type(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`prefix` can only be used on enums converted to and from `String` or `Cow<str>`",
+                                ));
+                            }
+                            if name_prefix.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            name_prefix = Some(lit);
+                        }
+                        "normalize" => {
+                            let path: Path = input.parse()?;
+                            if !is_string_type(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`normalize` can only be used on enums converted to and from `String`",
+                                ));
+                            }
+                            if normalize.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            normalize = Some(path);
+                        }
+                        "on_unknown" => {
+                            let path: Path = input.parse()?;
+                            if on_unknown.replace((option.clone(), path)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "convert_with" => {
+                            let path: Path = input.parse()?;
+                            if convert_with.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            convert_with = Some((option, path));
+                        }
+                        "valid" | "invalid" => {
+                            let duplicate = if option == "valid" {
+                                valid.replace((option.clone(), input.parse()?)).is_some()
+                            } else {
+                                invalid.replace(input.parse()?).is_some()
+                            };
+                            if !is_primitive_int(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!(
+                                        "`{}` can only be used with primitive integer types",
+                                        option
+                                    ),
+                                ));
+                            }
+                            if duplicate {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "known" => {
+                            let ident = input.parse()?;
+                            if !is_primitive_int(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`known` can only be used with primitive integer types",
+                                ));
+                            }
+                            if known_enum.replace(ident).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "writer" => {
+                            let ident = input.parse()?;
+                            if !is_string_type(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`writer` can only be used with `String`, as it collects text",
+                                ));
+                            }
+                            if writer.replace((option.clone(), ident)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "wrapper" => {
+                            let ident = input.parse()?;
+                            if !is_primitive_int(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`wrapper` can only be used with primitive integer types, as the enum keeps its discriminants",
+                                ));
+                            }
+                            if wrapper.replace(ident).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "vis" => {
+                            if vis.replace(input.parse()?).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "ident" => {
+                            let ident = input.parse()?;
+                            if other_ident.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "the other variant is already named",
+                                ));
+                            }
+                            other_ident = Some(ident);
+                        }
+                        "named_other" => {
+                            let content;
+                            bracketed!(content in input);
+                            if other_field_names.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "the fields of the other variant are already named",
+                                ));
+                            }
+                            other_field_names = Some(content.parse_terminated(Ident::parse)?);
+                        }
+                        "arity" => {
+                            let lit: LitInt = input.parse()?;
+                            if matches!(data_type, Type::Tuple(_)) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`arity` is only needed for aliases of tuples, which the macro cannot look into",
+                                ));
+                            }
+                            let elements = lit.base10_parse::<usize>()?;
+                            if elements == 0 {
+                                return Err(Error::new_spanned(
+                                    lit,
+                                    "the unit type has no elements to flatten",
+                                ));
+                            }
+                            if arity.replace((option.clone(), elements)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "from_tuple" => {
+                            let tuple = match input.parse()? {
+                                Type::Tuple(tuple) if !tuple.elems.is_empty() => tuple,
+                                ty => {
+                                    return Err(Error::new_spanned(
+                                        ty,
+                                        "expected a tuple of the types converting to each element of the data type",
+                                    ))
+                                }
+                            };
+                            if from_tuple.replace((option.clone(), tuple)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "fallback_into" => {
+                            let ty: Type = input.parse()?;
+                            if matches!(data_type, Type::Tuple(_)) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`fallback_into` cannot be used with tuple types",
+                                ));
+                            }
+                            if fallback_into.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            fallback_into = Some((option, ty));
+                        }
+                        "store" => {
+                            let ty: Type = input.parse()?;
+                            if matches!(data_type, Type::Tuple(_)) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`store` cannot be used with tuple types",
+                                ));
+                            }
+                            if store.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            store = Some((option, ty));
+                        }
+                        "inner" => {
+                            let ty: Type = input.parse()?;
+                            if !matches!(data_type, Type::Path(_)) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`inner` can only be used with tuple structs wrapping the value, such as `Wrapping<u16>`",
+                                ));
+                            }
+                            if inner.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            if !is_primitive_int(&ty) {
+                                return Err(Error::new_spanned(
+                                    ty,
+                                    "`inner` must be a primitive integer type",
+                                ));
+                            }
+                            inner = Some((option, ty));
+                        }
+                        "mask" => {
+                            let value: Expr = input.parse()?;
+                            if !is_primitive_int(&data_type) {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "`mask` can only be used with primitive integer types",
+                                ));
+                            }
+                            if parse_int_expr(&value)?.is_none() {
+                                return Err(Error::new_spanned(
+                                    value,
+                                    "`mask` must be an integer the macro can evaluate",
+                                ));
+                            }
+                            if mask.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            mask = Some((option, value));
+                        }
+                        "position" => {
+                            let value = input.parse()?;
+                            if position.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                            position = Some((option, value));
+                            return Ok(());
+                        }
+                        _ => return Err(unknown_option(&option)),
+                    }
+                    return Ok(());
+                }
+                if input.peek(token::Paren) {
+                    let content;
+                    match option.to_string().as_str() {
+                        "attrs" => {
+                            parenthesized!(content in input);
+                            let metas = Punctuated::<Meta, Token![,]>::parse_terminated(&content)?;
+                            other_attrs.extend(metas.into_iter().map(|meta| -> Attribute {
+                                parse_quote! { #[#meta] }
+                            }));
+                        }
+                        "known_attrs" => {
+                            parenthesized!(content in input);
+                            let metas = Punctuated::<Meta, Token![,]>::parse_terminated(&content)?;
+                            let attrs = metas
+                                .into_iter()
+                                .map(|meta| -> Attribute {
+                                    parse_quote! { #[#meta] }
+                                })
+                                .collect::<Vec<_>>();
+                            if known_attrs.replace((option.clone(), attrs)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "wrapper_attrs" => {
+                            parenthesized!(content in input);
+                            let metas = Punctuated::<Meta, Token![,]>::parse_terminated(&content)?;
+                            let attrs = metas
+                                .into_iter()
+                                .map(|meta| -> Attribute {
+                                    parse_quote! { #[#meta] }
+                                })
+                                .collect::<Vec<_>>();
+                            if wrapper_attrs.replace((option.clone(), attrs)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "impl_trait" => {
+                            parenthesized!(content in input);
+                            let parsed = parse_impl_trait(&option, &content)?;
+                            if impl_trait.replace(parsed).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        "fallbacks" => {
+                            parenthesized!(content in input);
+                            let parsed = parse_fallbacks(&option, &content)?;
+                            if fallbacks.replace((option.clone(), parsed)).is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ));
+                            }
+                        }
+                        _ => return Err(unknown_option(&option)),
+                    }
+                    return Ok(());
+                }
+
+                let flag = match option.to_string().as_str() {
+                    "bitmask" => &mut bitmask,
+                    "from_name_ci" => &mut from_name_ci,
+                    "case_insensitive" if !is_string_type(&data_type) => return Err(Error::new(
+                        option.span(),
+                        "`case_insensitive` can only be used on enums converted to and from `String`",
+                    )),
+                    "case_insensitive" => &mut case_insensitive,
+                    "serde" if !cfg!(feature = "serde") => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`serde` requires the `serde` feature of `enum-other` to be enabled",
+                        ))
+                    }
+                    "serde" if !is_string_type(&data_type) => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`serde` can only be used on enums converted to and from `String`",
+                        ))
+                    }
+                    "serde" => &mut serde,
+                    "serde_json" if !cfg!(feature = "serde_json") => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`serde_json` requires the `serde_json` feature of `enum-other` to be enabled",
+                        ))
+                    }
+                    "serde_json"
+                        if !(is_primitive_int(&data_type) && int_bounds(&data_type).is_some()
+                            || is_string_type(&data_type)
+                            || is_cow_str_type(&data_type)) =>
+                    {
+                        return Err(Error::new(
+                            option.span(),
+                            "`serde_json` can only be used on enums converted to and from integers of at most 64 bits or strings",
+                        ))
+                    }
+                    // Checked once `from_ref` is known.
+                    "serde_json" => {
+                        serde_json_span = Some(option.span());
+                        &mut serde_json
+                    }
+                    "clap" if !cfg!(feature = "clap") => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`clap` requires the `clap` feature of `enum-other` to be enabled",
+                        ))
+                    }
+                    "clap" if !is_string_type(&data_type) => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`clap` can only be used on enums converted to and from `String`",
+                        ))
+                    }
+                    "clap" => &mut clap,
+                    "phf" if !cfg!(feature = "phf") => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`phf` requires the `phf` feature of `enum-other` to be enabled",
+                        ))
+                    }
+                    "phf" if !is_string_type(&data_type) => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`phf` can only be used on enums converted to and from `String`",
+                        ))
+                    }
+                    "phf" => &mut phf,
+                    "repr" if !is_primitive_int(&data_type) => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`repr` can only be used with primitive integer types",
+                        ))
+                    }
+                    "repr" => &mut repr,
+                    "non_exhaustive" => &mut non_exhaustive,
+                    "indexable" if !matches!(&data_type, Type::Path(TypePath { qself: None, path }) if path.is_ident("usize")) => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`indexable` can only be used with `usize`",
+                        ))
+                    }
+                    "indexable" => &mut indexable,
+                    "into_iter" if tuple_element_type(&data_type).is_none() => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`into_iter` can only be used with tuples of a single element type",
+                        ))
+                    }
+                    "into_iter" => &mut into_iter,
+                    "from_iter" if tuple_element_type(&data_type).is_none() => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`from_iter` can only be used with tuples of a single element type",
+                        ))
+                    }
+                    "from_iter" => &mut from_iter,
+                    "doc_table" => &mut doc_table,
+                    "doc_aliases" => &mut doc_aliases,
+                    "error" if matches!(data_type, Type::Tuple(_)) => {
+                        return Err(Error::new(
+                            option.span(),
+                            "`error` cannot be used with tuple types, which do not implement `Display`",
+                        ))
+                    }
+                    "error" => &mut error,
+                    "deny_unknown" => &mut deny_unknown,
+                    "default_other" => &mut default_other,
+                    "boxed" => &mut boxed,
+                    // Checked once the wrapped type is known.
+                    "wrapping" => {
+                        wrapping_span = Some(option.span());
+                        &mut wrapping
+                    }
+                    "from_ref" => &mut from_ref,
+                    "deprecate_other" => &mut deprecate_other,
+                    "hide_other" => &mut hide_other,
+                    "store_normalized" => &mut store_normalized,
+                    "display" => &mut display,
+                    "exhaustive" if int_bounds(&data_type)
+                        .filter(|(min, max)| max - min <= 0xffff)
+                        .is_none() =>
+                    {
+                        return Err(Error::new(
+                            option.span(),
+                            "`exhaustive` can only be checked for primitive integer types of at most 16 bits",
+                        ))
+                    }
+                    "strict" | "exhaustive" if strict.is_none() => {
+                        exhaustive = option == "exhaustive";
+                        strict = Some(option);
+                        return Ok(());
+                    }
+                    "strict" | "exhaustive" => {
+                        let message = match &strict {
+                            Some(strict) if *strict != option => {
+                                "`exhaustive` enums are already `strict`".to_owned()
+                            }
+                            _ => format!("duplicate option `{}`", option),
+                        };
+                        return Err(Error::new(option.span(), message));
+                    }
+                    "from_only" | "into_only" | "no_impls" => {
+                        match &only {
+                            Some(previous) if *previous == option => {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("duplicate option `{}`", option),
+                                ))
+                            }
+                            Some(previous) => {
+                                return Err(Error::new(
+                                    option.span(),
+                                    format!("`{}` cannot be combined with `{}`", option, previous),
+                                ))
+                            }
+                            None => only = Some(option),
+                        }
+                        return Ok(());
+                    }
+                    _ if first => {
+                        if input.peek(token::Brace) {
+                            let content;
+                            braced!(content in input);
+                            if other_field_names.is_some() {
+                                return Err(Error::new(
+                                    option.span(),
+                                    "the fields of the other variant are already named",
+                                ));
+                            }
+                            other_field_names = Some(content.parse_terminated(Ident::parse)?);
+                        }
+                        given.pop();
+                        other_ident = Some(option);
+                        return Ok(());
+                    }
+                    _ => return Err(unknown_option(&option)),
+                };
+                if *flag {
+                    return Err(Error::new(
+                        option.span(),
+                        format!("duplicate option `{}`", option),
+                    ));
+                }
+                *flag = true;
+                Ok(())
+            };
+            match parse_option() {
+                Ok(()) => {}
+                // The option was read to its end, so the next ones can still
+                // be checked.
+                Err(error) if input.is_empty() || input.peek(Token![,]) => errors.push(error),
+                Err(error) => {
+                    errors.push(error);
+                    break;
+                }
+            }
+            first = false;
+        }
+        errors.finish()?;
+
+        if phf && case_insensitive {
+            errors.push(Error::new(
+                Span::call_site(),
+                "`phf` looks strings up exactly, so it cannot be combined with `case_insensitive`",
+            ));
+        }
+        if store_normalized && normalize.is_none() {
+            errors.push(Error::new(
+                Span::call_site(),
+                "`store_normalized` requires a `normalize` function",
+            ));
+        }
+        if let (Some(strict), Some(other_ident)) = (&strict, &other_ident) {
+            let mut error = Error::new(
+                other_ident.span(),
+                format!(
+                    "`strict` enums have no fallback variant, so `{}` cannot be used",
+                    other_ident
+                ),
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some(strict), Some((fallbacks, _))) = (&strict, &fallbacks) {
+            let mut error =
+                Error::new(fallbacks.span(), "`strict` enums have no fallback variants");
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some(other_ident), Some((fallbacks, _))) = (&other_ident, &fallbacks) {
+            let mut error = Error::new(
+                other_ident.span(),
+                "the variant holding the remaining values is already named by `fallbacks`",
+            );
+            error.combine(Error::new(fallbacks.span(), "`fallbacks` given here"));
+            errors.push(error);
+        }
+        let known_attrs = match (&known_enum, known_attrs) {
+            (None, Some((option, _))) => {
+                errors.push(Error::new(
+                    option.span(),
+                    "`known_attrs` requires a companion enum named with `known`",
+                ));
+                Vec::new()
+            }
+            (_, known_attrs) => known_attrs.map_or_else(Vec::new, |(_, attrs)| attrs),
+        };
+        let wrapper_attrs = match (&wrapper, wrapper_attrs) {
+            (None, Some((option, _))) => {
+                errors.push(Error::new(
+                    option.span(),
+                    "`wrapper_attrs` requires a wrapper enum named with `wrapper`",
+                ));
+                Vec::new()
+            }
+            (_, wrapper_attrs) => wrapper_attrs.map_or_else(Vec::new, |(_, attrs)| attrs),
+        };
+        if let Some(wrapper) = &wrapper {
+            let kept = ["ident", "vis", "wrapper", "wrapper_attrs"];
+            if let Some(option) = given
+                .iter()
+                .find(|option| !kept.contains(&option.to_string().as_str()))
+            {
+                let mut error = Error::new(
+                    option.span(),
+                    format!(
+                        "`{}` cannot be combined with `wrapper`, which leaves the enum as written",
+                        option
+                    ),
+                );
+                error.combine(Error::new(wrapper.span(), "`wrapper` given here"));
+                errors.push(error);
+            }
+            if let Some(names) = &other_field_names {
+                errors.push(Error::new_spanned(
+                    names,
+                    "the other variant of the wrapper holds the value as a single field",
+                ));
+            }
+            if let Some(other_ident) = other_ident.as_ref().filter(|ident| *ident == "Known") {
+                errors.push(Error::new(
+                    other_ident.span(),
+                    "`Known` is already the variant of the wrapper holding the enum",
+                ));
+            }
+        }
+        let invalid = match (invalid, valid) {
+            (Some(invalid), Some((_, valid))) => Some((invalid, valid)),
+            (None, None) => None,
+            (Some(invalid), None) => {
+                errors.push(Error::new(
+                    invalid.span(),
+                    "`invalid` requires a `valid` range for values outside of it",
+                ));
+                None
+            }
+            (None, Some((option, _))) => {
+                errors.push(Error::new(
+                    option.span(),
+                    "`valid` requires an `invalid` variant for values outside of it",
+                ));
+                None
+            }
+        };
+        let (other_ident, fallbacks) = match fallbacks {
+            Some((_, (catch_all, fallbacks))) => (Some(catch_all), fallbacks),
+            None => (other_ident, Vec::new()),
+        };
+        if let (Some(strict), Some((fallback_into, _))) = (&strict, &fallback_into) {
+            let mut error = Error::new(
+                fallback_into.span(),
+                "`strict` enums have no fallback variant to hold another enum",
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some(strict), Some(decode), false) = (
+            &strict,
+            impl_trait
+                .as_ref()
+                .and_then(|impl_trait| impl_trait.decode.as_ref()),
+            exhaustive,
+        ) {
+            let mut error = Error::new(
+                decode.span(),
+                "`strict` enums have no infallible conversion to decode with",
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        let value_type = inner
+            .as_ref()
+            .map(|(_, ty)| ty)
+            .or_else(|| wrapped_int_type(&data_type))
+            .unwrap_or(&data_type);
+        if let (Some(span), None) = (wrapping_span, int_bounds(value_type)) {
+            errors.push(Error::new(
+                span,
+                "`wrapping` can only be used with primitive integer types of at most 64 bits",
+            ));
+        }
+        if let (Some(span), false, false) = (serde_json_span, from_ref, is_copy_type(&data_type)) {
+            errors.push(Error::new(
+                span,
+                "`serde_json` needs `from_ref` to encode string enums from a reference\n\
+                 help: add `from_ref` to the macro arguments",
+            ));
+        }
+        if let (Some(strict), Some((store, _))) = (&strict, &store) {
+            let mut error = Error::new(
+                store.span(),
+                "`strict` enums have no fallback variant to store the value in",
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some((store, _)), Some((fallback_into, _))) = (&store, &fallback_into) {
+            let mut error = Error::new(
+                store.span(),
+                "`store` cannot be combined with `fallback_into`, which already sets the type held by the fallback variant",
+            );
+            error.combine(Error::new(
+                fallback_into.span(),
+                "`fallback_into` given here",
+            ));
+            errors.push(error);
+        }
+        if let (Some(strict), true) = (&strict, boxed) {
+            errors.push(Error::new(
+                strict.span(),
+                "`strict` enums have no fallback variant to box the value in",
+            ));
+        }
+        if let (true, Some((option, _))) = (boxed, store.as_ref().or(fallback_into.as_ref())) {
+            errors.push(Error::new(
+                option.span(),
+                format!(
+                    "`{}` cannot be combined with `boxed`, which already sets the type held by the fallback variant",
+                    option
+                ),
+            ));
+        }
+        if let Some((option, tuple)) = &from_tuple {
+            let elements = match &data_type {
+                Type::Tuple(TypeTuple { elems, .. }) => Some(elems.len()),
+                _ => arity.as_ref().map(|(_, elements)| *elements),
+            };
+            match elements {
+                None => {
+                    errors.push(Error::new(
+                        option.span(),
+                        "`from_tuple` requires a tuple data type, or `arity` for an alias of one",
+                    ));
+                }
+                Some(elements) if elements != tuple.elems.len() => {
+                    errors.push(Error::new_spanned(
+                        tuple,
+                        format!(
+                            "expected {} types, one for each element of the data type",
+                            elements
+                        ),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        if let (Some((arity, _)), Some((store, _))) = (&arity, &store) {
+            let mut error = Error::new(store.span(), "`store` cannot be used with tuple types");
+            error.combine(Error::new(arity.span(), "`arity` given here"));
+            errors.push(error);
+        }
+        if let (Some((arity, _)), Some((fallback_into, _))) = (&arity, &fallback_into) {
+            let mut error = Error::new(
+                fallback_into.span(),
+                "`fallback_into` cannot be used with tuple types",
+            );
+            error.combine(Error::new(arity.span(), "`arity` given here"));
+            errors.push(error);
+        }
+        if let (Some(strict), Some((position, _))) = (&strict, &position) {
+            let mut error = Error::new(
+                position.span(),
+                "`strict` enums have no fallback variant to position",
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some(strict), Some((convert_with, _))) = (&strict, &convert_with) {
+            let mut error = Error::new(
+                convert_with.span(),
+                "`convert_with` requires the fallback variant that `strict` leaves out",
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some(strict), true) = (&strict, deprecate_other || hide_other) {
+            errors.push(Error::new(
+                strict.span(),
+                "`strict` enums have no fallback variant to deprecate or hide",
+            ));
+        }
+        if let (Some(strict), true) = (&strict, store_normalized) {
+            errors.push(Error::new(
+                strict.span(),
+                "`strict` enums have no fallback variant to store normalized strings in",
+            ));
+        }
+        if let (Some(strict), Some((on_unknown, _))) = (&strict, &on_unknown) {
+            let mut error = Error::new(
+                on_unknown.span(),
+                "`strict` enums reject unknown values instead of decoding them",
+            );
+            error.combine(Error::new(
+                strict.span(),
+                format!("`{}` given here", strict),
+            ));
+            errors.push(error);
+        }
+        if let (Some(strict), true) = (&strict, deny_unknown) {
+            errors.push(Error::new(
+                strict.span(),
+                "`strict` enums have no fallback variant for `deny_unknown` to guard",
+            ));
+        }
+        if let (Some(strict), true) = (&strict, from_iter) {
+            errors.push(Error::new(
+                strict.span(),
+                "`from_iter` requires the infallible conversion that `strict` leaves out",
+            ));
+        }
+        if let (Some(strict), true) = (&strict, default_other) {
+            errors.push(Error::new(
+                strict.span(),
+                "`strict` enums have no fallback variant to default to",
+            ));
+        }
+        if let (Some(strict), true) = (&strict, bitmask) {
+            errors.push(Error::new(
+                strict.span(),
+                "`bitmask` enums cannot be `strict`, as every bit pattern must be representable",
+            ));
+        }
+        if let (Some((mask, _)), true) = (&mask, bitmask) {
+            errors.push(Error::new(
+                mask.span(),
+                "`mask` cannot be combined with `bitmask`, whose variants are single flags",
+            ));
+        }
+        if let (Some((mask, _)), true) = (&mask, exhaustive) {
+            errors.push(Error::new(
+                mask.span(),
+                "`exhaustive` cannot check the bits left out by `mask`",
+            ));
+        }
+        if let (Some((mask, _)), Some((invalid, _))) = (&mask, &invalid) {
+            let mut error = Error::new(
+                mask.span(),
+                "`mask` cannot be combined with an `invalid` variant, which holds whole values",
+            );
+            error.combine(Error::new(invalid.span(), "`invalid` given here"));
+            errors.push(error);
+        }
+
+        let (encode, decode) = match only.map(|only| only.to_string()).as_deref() {
+            Some("from_only") => (false, true),
+            Some("into_only") => (true, false),
+            Some(_) => (false, false),
+            None => (true, true),
+        };
+        if let (true, false) = (from_iter, decode) {
+            errors.push(Error::new(
+                Span::call_site(),
+                "`from_iter` requires the conversion into the enum",
+            ));
+        }
+        if let (Some((writer, _)), false) = (&writer, decode) {
+            errors.push(Error::new(
+                writer.span(),
+                "`writer` converts the text it collects, which requires the conversion into the enum",
+            ));
+        }
+
+        errors.finish()?;
 
         Ok(Self {
             data_type,
             other_ident: other_ident.unwrap_or_else(|| parse_quote! { Other }),
+            other_field_names,
+            other_attrs,
+            position,
+            fallbacks,
+            invalid,
+            bitmask,
+            mask: mask.map(|(_, value)| value),
+            from_name_ci,
+            case_insensitive,
+            display,
+            serde,
+            serde_json,
+            clap,
+            phf,
+            repr,
+            non_exhaustive,
+            indexable,
+            into_iter,
+            from_iter,
+            doc_table,
+            doc_aliases,
+            error,
+            deny_unknown,
+            default_other,
+            boxed,
+            wrapping,
+            from_ref,
+            deprecate_other,
+            hide_other,
+            encode,
+            decode,
+            strict: strict.is_some(),
+            exhaustive,
+            rename_all,
+            name_prefix,
+            convert_with: convert_with.map(|(_, path)| path),
+            fallback_into: fallback_into.map(|(_, ty)| ty),
+            store: store.map(|(_, ty)| ty),
+            inner: inner.map(|(_, ty)| ty),
+            known_enum,
+            writer: writer.map(|(_, ident)| ident),
+            impl_trait,
+            arity: arity.map(|(_, elements)| elements),
+            from_tuple: from_tuple.map(|(_, tuple)| tuple),
+            vis,
+            known_attrs,
+            wrapper,
+            wrapper_attrs,
+            normalize,
+            on_unknown: on_unknown.map(|(_, path)| path),
+            store_normalized,
+        })
+    }
+}
+
+/// Parses the `Name = range` variants given to `fallbacks(...)`, along with the
+/// `Name = _` variant holding the remaining values.
+fn parse_fallbacks(option: &Ident, input: ParseStream) -> Result<(Ident, Vec<Variant>)> {
+    let mut catch_all: Option<Ident> = None;
+    let mut variants = Vec::new();
+    while !input.is_empty() {
+        let ident: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        if input.peek(Token![_]) {
+            let underscore = input.parse::<Token![_]>()?;
+            if let Some(first) = &catch_all {
+                let mut error = Error::new(
+                    underscore.span,
+                    "only one fallback can hold the remaining values",
+                );
+                error.combine(Error::new(first.span(), "first given here"));
+                return Err(error);
+            }
+            catch_all = Some(ident);
+        } else {
+            let range: ExprRange = input.parse()?;
+            variants.push(parse_quote! { #ident = #range });
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    let catch_all = catch_all.ok_or_else(|| {
+        Error::new(
+            option.span(),
+            "expected a fallback holding the remaining values, given as `Name = _`",
+        )
+    })?;
+    Ok((catch_all, variants))
+}
+
+/// Parses the `path = Trait` of `impl_trait(...)`, along with the methods to
+/// `encode` and `decode` with.
+fn parse_impl_trait(option: &Ident, input: ParseStream) -> Result<ImplTrait> {
+    let mut path = None;
+    let mut encode = None;
+    let mut decode = None;
+    while !input.is_empty() {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let duplicate = match key.to_string().as_str() {
+            "path" => path.replace(input.parse::<Path>()?).is_some(),
+            "encode" => encode.replace(input.parse::<Ident>()?).is_some(),
+            "decode" => decode.replace(input.parse::<Ident>()?).is_some(),
+            _ => {
+                return Err(Error::new(
+                    key.span(),
+                    format!(
+                        "unknown option `{}`, expected one of `path`, `encode`, `decode`",
+                        key
+                    ),
+                ))
+            }
+        };
+        if duplicate {
+            return Err(Error::new(
+                key.span(),
+                format!("duplicate option `{}`", key),
+            ));
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    let path = path.ok_or_else(|| {
+        Error::new(
+            option.span(),
+            "expected `path = ...` giving the trait to implement",
+        )
+    })?;
+    if encode.is_none() && decode.is_none() {
+        return Err(Error::new(
+            option.span(),
+            "expected `encode = ...` or `decode = ...` naming the methods of the trait",
+        ));
+    }
+    Ok(ImplTrait {
+        path,
+        encode,
+        decode,
+    })
+}
+
+/// Returns the "other" variant with `values` for its fields, which are named if
+/// `names` is given.
+fn other_variant(
+    ident: &Ident,
+    names: Option<&Punctuated<Ident, Token![,]>>,
+    values: impl IntoIterator<Item = impl ToTokens>,
+) -> TokenStream2 {
+    let values = values.into_iter();
+    match names {
+        Some(names) => {
+            let names = names.iter();
+            quote! { #ident { #(#names: #values),* } }
+        }
+        None => quote! { #ident(#(#values),*) },
+    }
+}
+
+/// Returns whether `ty` names `String`.
+fn is_string_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            matches!(
+                path.segments.last(),
+                Some(segment) if segment.ident == "String" && segment.arguments.is_empty()
+            )
+        }
+        Type::Group(group) => is_string_type(&group.elem),
+        Type::Paren(paren) => is_string_type(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Returns the integer type wrapped by `ty` if it names `Wrapping` or
+/// `Saturating`, which are matched through their field.
+fn wrapped_int_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    match (segment.ident.to_string().as_str(), &segment.arguments) {
+        ("Wrapping" | "Saturating", PathArguments::AngleBracketed(args))
+            if args.args.len() == 1 =>
+        {
+            match &args.args[0] {
+                GenericArgument::Type(inner) if is_primitive_int(inner) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether `ty` names `Cow<str>`, which borrows the strings of known
+/// variants.
+fn is_cow_str_type(ty: &Type) -> bool {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => match path.segments.last() {
+            Some(segment) if segment.ident == "Cow" => segment,
+            _ => return false,
+        },
+        Type::Group(group) => return is_cow_str_type(&group.elem),
+        Type::Paren(paren) => return is_cow_str_type(&paren.elem),
+        _ => return false,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| {
+            matches!(arg, GenericArgument::Type(Type::Path(TypePath { qself: None, path })) if path.is_ident("str"))
+        }),
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` names `Vec<u8>` or `Box<[u8]>`, which are matched as
+/// byte slices.
+fn is_bytes_type(ty: &Type) -> bool {
+    let is_u8 =
+        |ty: &Type| matches!(ty, Type::Path(TypePath { qself: None, path }) if path.is_ident("u8"));
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            let segment = match path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+            let arg = match &segment.arguments {
+                PathArguments::AngleBracketed(args) if args.args.len() == 1 => &args.args[0],
+                _ => return false,
+            };
+            match (segment.ident.to_string().as_str(), arg) {
+                ("Vec", GenericArgument::Type(elem)) => is_u8(elem),
+                ("Box", GenericArgument::Type(Type::Slice(slice))) => is_u8(&slice.elem),
+                _ => false,
+            }
+        }
+        Type::Group(group) => is_bytes_type(&group.elem),
+        Type::Paren(paren) => is_bytes_type(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Removes every `#[name(...)]` attribute from `attrs`, returning the
+/// attributes listed inside of them.
+fn extract_attrs(attrs: &mut Vec<Attribute>, name: &str) -> Result<Vec<Attribute>> {
+    let mut extracted = Vec::new();
+    let mut errors = Errors::default();
+
+    attrs.retain(|attr| {
+        if !attr.path.is_ident(name) {
+            return true;
+        }
+
+        match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            Ok(metas) => extracted.extend(metas.into_iter().map(|meta| parse_quote! { #[#meta] })),
+            Err(e) => errors.push(e),
+        }
+        false
+    });
+
+    errors.finish().map(|()| extracted)
+}
+
+/// Returns the attributes of a generated item.
+fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+/// Returns the signed counterpart of `data_type` if it is an unsigned primitive
+/// integer.
+fn signed_counterpart(data_type: &Type) -> Option<&'static str> {
+    let ident = match data_type {
+        Type::Path(TypePath { qself: None, path }) => path.get_ident()?,
+        _ => return None,
+    };
+
+    Some(match ident.to_string().as_str() {
+        "u8" => "i8",
+        "u16" => "i16",
+        "u32" => "i32",
+        "u64" => "i64",
+        "u128" => "i128",
+        "usize" => "isize",
+        _ => return None,
+    })
+}
+
+/// Returns the smallest and largest values of `data_type` if it is a primitive
+/// integer type of at most 64 bits.
+fn int_bounds(data_type: &Type) -> Option<(i128, i128)> {
+    let ident = match data_type {
+        Type::Path(TypePath { qself: None, path }) => path.get_ident()?,
+        _ => return None,
+    };
+
+    Some(match ident.to_string().as_str() {
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" => (0, u32::MAX.into()),
+        "u64" => (0, u64::MAX.into()),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        _ => return None,
+    })
+}
+
+/// Returns the aliases given with `#[doc(alias = "...")]` or
+/// `#[doc(alias("...", ...))]`.
+fn existing_doc_aliases(attrs: &[Attribute]) -> Vec<String> {
+    let items = |meta: Meta| match meta {
+        Meta::List(list) => list.nested.into_iter().collect(),
+        _ => Vec::new(),
+    };
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .flat_map(items)
+        .flat_map(|item| match item {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            })) if path.is_ident("alias") => vec![lit.value()],
+            NestedMeta::Meta(meta @ Meta::List(_)) if meta.path().is_ident("alias") => items(meta)
+                .into_iter()
+                .filter_map(|item| match item {
+                    NestedMeta::Lit(Lit::Str(lit)) => Some(lit.value()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Returns the primitive integer type given in a `#[repr(...)]`, if any.
+fn repr_int(attrs: &[Attribute]) -> Option<Ident> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
         })
+        .flatten()
+        .find_map(|meta| match meta {
+            Meta::Path(path) => path
+                .get_ident()
+                .filter(|ident| is_primitive_int(&parse_quote! { #ident }))
+                .cloned(),
+            _ => None,
+        })
+}
+
+/// Returns whether `data_type` is made up of primitive types known to be `Copy`.
+fn is_copy_type(data_type: &Type) -> bool {
+    match data_type {
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter().all(is_copy_type),
+        Type::Array(array) => is_copy_type(&array.elem),
+        Type::Group(group) => is_copy_type(&group.elem),
+        Type::Paren(paren) => is_copy_type(&paren.elem),
+        Type::Path(TypePath { qself: None, path }) => {
+            is_primitive_int(data_type)
+                || ["bool", "char", "f32", "f64"]
+                    .iter()
+                    .any(|name| path.is_ident(name))
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether `data_type` is a primitive integer type.
+fn is_primitive_int(data_type: &Type) -> bool {
+    let ident = match data_type {
+        Type::Path(TypePath { qself: None, path }) => match path.get_ident() {
+            Some(ident) => ident,
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    matches!(
+        ident.to_string().as_str(),
+        "u8" | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+    )
+}
+
+/// Reports an error if `value` is negative while `data_type` is unsigned.
+fn check_sign(
+    errors: &mut Errors,
+    data_type: &Type,
+    value: i128,
+    tokens: impl ToTokens,
+    help: &str,
+) {
+    if let (Some(signed_type), true) = (signed_counterpart(data_type), value < 0) {
+        errors.push(Error::new_spanned(
+            tokens,
+            format!(
+                "discriminant {} is negative but `{}` is unsigned\n\
+                 help: change the type to `{}` or {}",
+                value,
+                quote! { #data_type },
+                signed_type,
+                help,
+            ),
+        ));
     }
 }
 
-fn parse_int_expr(expr: &Expr) -> Result<Option<isize>> {
-    let mut expr = expr;
-    let mut negative = false;
+/// Reports an error if `value` does not fit in `data_type`. Negative values of
+/// unsigned types are left to `check_sign`.
+fn check_bounds(errors: &mut Errors, data_type: &Type, value: i128, tokens: impl ToTokens) {
+    match int_bounds(data_type) {
+        Some((min, max)) if value > max || (value < min && min < 0) => {
+            errors.push(Error::new_spanned(
+                tokens,
+                format!(
+                    "discriminant {} is out of range for `{}`, which holds {} to {}",
+                    value,
+                    quote! { #data_type },
+                    min,
+                    max,
+                ),
+            ));
+        }
+        _ => (),
+    }
+}
 
-    if let Expr::Unary(ExprUnary {
-        op: UnOp::Neg(_),
-        expr: expression,
-        ..
-    }) = expr
+/// Checks that an array expression has as many elements as `data_type`, and
+/// that the integers in it fit in the element type.
+fn check_array(errors: &mut Errors, data_type: &Type, expr: &Expr) {
+    let (array_type, array) = match (data_type, expr) {
+        (Type::Array(array_type), Expr::Array(array)) => (array_type, array),
+        _ => return,
+    };
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Int(len), ..
+    }) = &array_type.len
     {
-        negative = true;
-        expr = expression;
+        if len.base10_parse::<usize>().ok() != Some(array.elems.len()) {
+            errors.push(Error::new_spanned(
+                array,
+                format!(
+                    "array of length {} given for `{}`",
+                    array.elems.len(),
+                    quote! { #data_type },
+                ),
+            ));
+        }
     }
+    for elem in &array.elems {
+        match parse_int_expr(elem) {
+            Ok(Some(int)) => check_bounds(errors, &array_type.elem, int, elem),
+            _ => check_array(errors, &array_type.elem, elem),
+        }
+    }
+}
 
+/// Returns a key that is equal for two discriminants when they are known to
+/// denote the same value.
+fn discriminant_key(expr: &Expr, case_insensitive: bool) -> String {
     match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(string),
+            ..
+        }) if case_insensitive => format!("{:?}", string.value().to_ascii_lowercase()),
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(string),
+            ..
+        }) => format!("{:?}", string.value()),
+        Expr::Lit(ExprLit {
+            lit: Lit::ByteStr(bytes),
+            ..
+        }) => format!("{:?}", bytes.value()),
+        // Literals too large for the macro to evaluate.
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) if int.base10_parse::<i128>().is_err() => int.base10_digits().to_owned(),
+        // Byte strings dereferenced to arrays and arrays of the same bytes
+        // give the same key.
+        Expr::Unary(ExprUnary {
+            op: UnOp::Deref(_),
+            expr: inner,
+            ..
+        }) if matches!(
+            &**inner,
+            Expr::Lit(ExprLit {
+                lit: Lit::ByteStr(_),
+                ..
+            })
+        ) =>
+        {
+            discriminant_key(inner, false)
+        }
+        Expr::Array(array) => {
+            let elems = array
+                .elems
+                .iter()
+                .map(|elem| discriminant_key(elem, case_insensitive))
+                .collect::<Vec<_>>();
+            format!("[{}]", elems.join(", "))
+        }
+        _ => match parse_int_expr(expr) {
+            Ok(Some(int)) => int.to_string(),
+            _ => quote! { #expr }.to_string(),
+        },
+    }
+}
+
+/// Evaluates `expr` if it is an integer or byte literal, or an arithmetic
+/// expression made up of them. Values above `i128::MAX` are not evaluated.
+fn parse_int_expr(expr: &Expr) -> Result<Option<i128>> {
+    Ok(match expr {
         Expr::Lit(ExprLit {
             lit: Lit::Int(int), ..
-        }) => Ok(Some(
-            int.base10_parse::<isize>()? * if negative { -1 } else { 1 },
-        )),
-        _ => Ok(None),
+        }) => int.base10_parse::<i128>().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Byte(byte),
+            ..
+        }) => Some(byte.value().into()),
+        Expr::Paren(ExprParen { expr, .. }) | Expr::Group(ExprGroup { expr, .. }) => {
+            parse_int_expr(expr)?
+        }
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_int_expr(expr)?.and_then(i128::checked_neg),
+        Expr::Binary(ExprBinary {
+            left, op, right, ..
+        }) => {
+            let (left, right) = match (parse_int_expr(left)?, parse_int_expr(right)?) {
+                (Some(left), Some(right)) => (left, right),
+                _ => return Ok(None),
+            };
+            match op {
+                BinOp::Add(_) => left.checked_add(right),
+                BinOp::Sub(_) => left.checked_sub(right),
+                BinOp::Mul(_) => left.checked_mul(right),
+                BinOp::Div(_) => left.checked_div(right),
+                BinOp::Rem(_) => left.checked_rem(right),
+                // Shifting out bits, the sign bit included, overflows.
+                BinOp::Shl(_) => u32::try_from(right)
+                    .ok()
+                    .and_then(|right| left.checked_shl(right))
+                    .filter(|shifted| shifted >> right == left),
+                BinOp::Shr(_) => u32::try_from(right)
+                    .ok()
+                    .and_then(|right| left.checked_shr(right)),
+                BinOp::BitAnd(_) => Some(left & right),
+                BinOp::BitOr(_) => Some(left | right),
+                BinOp::BitXor(_) => Some(left ^ right),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// Returns whether `expr` is also valid in pattern position. Other
+/// discriminants are compared in match guards instead.
+fn is_pattern(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(&**expr, Expr::Lit(_)),
+        Expr::Paren(ExprParen { expr, .. }) | Expr::Group(ExprGroup { expr, .. }) => {
+            is_pattern(expr)
+        }
+        Expr::Tuple(ExprTuple { elems, .. }) | Expr::Array(ExprArray { elems, .. }) => {
+            elems.iter().all(is_pattern)
+        }
+        Expr::Range(ExprRange { from, to, .. }) => from
+            .iter()
+            .chain(to)
+            .all(|bound| is_pattern(bound) || matches!(&**bound, Expr::Path(_))),
+        _ => false,
     }
 }
 
@@ -140,7 +2002,8 @@ fn parse_int_expr(expr: &Expr) -> Result<Option<isize>> {
 /// `From<Type>` for `Enum`.
 ///
 /// As it generates match statements to implement `From`, the provided
-/// discriminants must be both valid expressions and patterns.
+/// discriminants are used as patterns where possible. Any other constant
+/// expression is compared with `==` in a match guard.
 ///
 /// # Examples
 ///
@@ -192,132 +2055,5040 @@ fn parse_int_expr(expr: &Expr) -> Result<Option<isize>> {
 /// assert_eq!(u16::from(Radix::Hexadecimal), 16);
 /// ```
 ///
-/// Automatic discriminant values are also available for types that fit in an
-/// `isize`:
+/// The identifier can also be given anywhere among the options as
+/// `ident = ...`. Options follow the data type in any order, and unknown ones
+/// are reported along with the valid ones:
 ///
 /// ```
-/// #[enum_other::other(u8)]
+/// #[enum_other::other(u16, display, ident = Unknown, from_ref)]
 /// #[derive(Debug, PartialEq, Eq)]
-/// pub enum Dimension {
-///     Point = 0,
-///     Line, // = 1
-///     Surface, // = 2
-///     Solid, // = 3
+/// pub enum Radix {
+///     Binary = 2,
+///     Octal = 8,
 /// }
 ///
-/// assert_eq!(Dimension::from(2), Dimension::Surface);
-/// assert_eq!(u8::from(Dimension::Point), 0);
+/// assert_eq!(Radix::from(3), Radix::Unknown(3));
+/// assert_eq!(Radix::Octal.to_string(), "Octal");
 /// ```
-#[proc_macro_attribute]
-pub fn other(args: TokenStream, item: TokenStream) -> TokenStream {
-    let mut item = parse_macro_input!(item as ItemEnum);
-    let Args {
-        data_type,
-        other_ident,
-    } = parse_macro_input!(args as Args);
-
-    let mut discriminants = Vec::with_capacity(item.variants.len());
-    let mut curr_discriminant = 0isize;
-    for mut variant in &mut item.variants {
-        discriminants.push(match &variant.discriminant {
-            Some((_, expr)) => {
-                match parse_int_expr(expr) {
-                    Ok(Some(int)) => curr_discriminant = int,
-                    Ok(None) => (),
-                    Err(e) => return TokenStream::from(e.to_compile_error()),
-                }
-                expr.clone()
-            }
-            None => ExprLit {
-                attrs: Vec::new(),
-                lit: LitInt::new(&curr_discriminant.to_string(), Span::call_site().into()).into(),
-            }
-            .into(),
-        });
-        variant.discriminant = None;
-        curr_discriminant += 1;
-    }
-
-    let mut other_fields = Punctuated::new();
-    match &data_type {
-        Type::Tuple(TypeTuple { elems, .. }) => other_fields = elems.clone(),
-        _ => other_fields.push_value(data_type.clone()),
-    };
-    item.variants
-        .push(parse_quote! { #other_ident(#other_fields) });
-
-    let other_fields_pattern = (0..other_fields.len())
-        .map(|i| format_ident!("_{}", i))
-        .collect::<Vec<Ident>>();
-
-    let primary_variants = item
-        .variants
-        .iter()
-        .map(|variant| variant.ident.clone())
-        .filter(|ident| *ident != other_ident)
-        .collect::<Vec<Ident>>();
-
-    let enum_ident = item.ident.clone();
-
-    let data_type_match = discriminants
-        .first()
-        .map(|discriminant| match discriminant {
+///
+/// Following the identifier with braced names gives its fields names, one per
+/// element of a tuple type:
+///
+/// ```
+/// #[enum_other::other((u8, u8), Unknown { major, minor })]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Version {
+///     First = (1, 0),
+///     Second = (2, 0),
+/// }
+///
+/// assert_eq!(Version::from((1, 1)), Version::Unknown { major: 1, minor: 1 });
+/// assert_eq!(<(u8, u8)>::from(Version::Unknown { major: 3, minor: 0 }), (3, 0));
+/// ```
+///
+/// The macro cannot look into type aliases, so an alias of a tuple is kept
+/// whole unless its number of elements is given with `arity = N`. The "other"
+/// variant then holds the elements as separate fields, naming their types
+/// through a hidden trait implemented for tuples, and giving an error naming
+/// the alias if it is not a tuple of that many elements:
+///
+/// ```
+/// type Rgb = (u8, u8, u8);
+///
+/// #[enum_other::other(Rgb, arity = 3)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Color {
+///     Black = (0, 0, 0),
+/// }
+///
+/// assert_eq!(Color::from((1, 2, 3)), Color::Other(1, 2, 3));
+/// assert_eq!(Rgb::from(Color::Other(1, 2, 3)), (1, 2, 3));
+/// ```
+///
+/// When the elements come from enums of their own, `from_tuple = (...)`
+/// names one type per element, and generates a conversion from a tuple of
+/// them. Each converts to its element, and the combined value is decoded as
+/// usual, through `TryFrom` for `strict` enums:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// pub enum Major {
+///     One = 1,
+/// }
+///
+/// #[enum_other::other(u8)]
+/// pub enum Minor {
+///     Zero = 0,
+///     One = 1,
+/// }
+///
+/// #[enum_other::other((u8, u8), from_tuple = (Major, Minor))]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum ProtocolVersion {
+///     Http11 = (1, 1),
+/// }
+///
+/// assert_eq!(ProtocolVersion::from((Major::One, Minor::One)), ProtocolVersion::Http11);
+/// assert_eq!(ProtocolVersion::from((Major::One, Minor::Zero)), ProtocolVersion::Other(1, 0));
+/// ```
+///
+/// Tuples of a single element type can pass `into_iter` to iterate over the
+/// elements of any variant:
+///
+/// ```
+/// #[enum_other::other((u8, u8, u8), into_iter)]
+/// pub enum Color {
+///     Red = (255, 0, 0),
+/// }
+///
+/// assert_eq!(Color::Red.into_iter().collect::<Vec<u8>>(), [255, 0, 0]);
+/// assert_eq!(Color::Other(1, 2, 3).into_iter().max(), Some(3));
+/// ```
+///
+/// They can also pass `from_iter` to collect the enum from exactly as many
+/// elements as the tuple has, panicking otherwise. `try_from_iter` returns
+/// `None` instead:
+///
+/// ```
+/// #[enum_other::other((u8, u8, u8), from_iter)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Color {
+///     Red = (255, 0, 0),
+/// }
+///
+/// assert_eq!([255, 0, 0].into_iter().collect::<Color>(), Color::Red);
+/// assert_eq!(Color::try_from_iter([1, 2]), None);
+/// ```
+///
+/// The names can also be given with `named_other = [...]`, keeping the
+/// default identifier:
+///
+/// ```
+/// #[enum_other::other((u8, u8, u8), named_other = [red, green, blue])]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Color {
+///     Black = (0, 0, 0),
+/// }
+///
+/// assert_eq!(Color::from((1, 2, 3)), Color::Other { red: 1, green: 2, blue: 3 });
+/// ```
+///
+/// Passing `default_other` implements `Default` by defaulting each field of
+/// the "other" variant, even when the default value has a variant of its own.
+/// It is not implemented unless asked for, as that would conflict with an
+/// `impl Default` the crate already writes by hand. To default to a named
+/// variant, derive `Default` and mark it with `#[default]` instead:
+///
+/// ```
+/// #[enum_other::other((u8, u8, u8), default_other)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Color {
+///     Red = (255, 0, 0),
+/// }
+///
+/// assert_eq!(Color::default(), Color::Other(0, 0, 0));
+/// ```
+///
+/// The "other" variant may also be declared in the enum, to document it or to
+/// choose its position. It must hold the data type, either as a tuple variant
+/// or with named fields:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Port {
+///     Http = 80,
+///     /// A port without a well-known service.
+///     Other(u16),
+///     Https = 443,
+/// }
+///
+/// assert_eq!(Port::from(8080), Port::Other(8080));
+/// ```
+///
+/// Otherwise it is added after the other variants, unless `position` is given
+/// as `first`, `last` or the index to insert it at:
+///
+/// ```
+/// #[enum_other::other(u8, position = first)]
+/// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// pub enum Priority {
+///     Low = 1,
+///     High = 2,
+/// }
+///
+/// assert!(Priority::Other(7) < Priority::Low);
+/// ```
+///
+/// Automatic discriminant values are also available for other integer types,
+/// counting up to at most `i128::MAX`:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Dimension {
+///     Point = 0,
+///     Line, // = 1
+///     Surface, // = 2
+///     Solid, // = 3
+/// }
+///
+/// assert_eq!(Dimension::from(2), Dimension::Surface);
+/// assert_eq!(u8::from(Dimension::Point), 0);
+/// ```
+///
+/// Counting past the largest value of a type of at most 64 bits is an error,
+/// unless `wrapping` is passed to continue from its smallest value:
+///
+/// ```
+/// #[enum_other::other(u8, wrapping)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Sequence {
+///     Last = 255,
+///     First, // = 0
+/// }
+///
+/// assert_eq!(Sequence::from(0), Sequence::First);
+/// ```
+///
+/// When converting to and from `String`, variants without a discriminant use
+/// their identifier instead:
+///
+/// ```
+/// #[enum_other::other(String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Protocol {
+///     Http, // = "Http"
+///     Https = "HTTPS",
+/// }
+///
+/// assert_eq!(String::from(Protocol::Http), "Http");
+/// assert_eq!(Protocol::from("HTTPS".to_string()), Protocol::Https);
+/// ```
+///
+/// The identifiers can be converted to another case style with `rename_all`,
+/// which takes one of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`,
+/// `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or
+/// `"SCREAMING-KEBAB-CASE"`:
+///
+/// ```
+/// #[enum_other::other(String, rename_all = "kebab-case")]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Header {
+///     ContentType, // = "content-type"
+///     Etag = "ETag",
+/// }
+///
+/// assert_eq!(String::from(Header::ContentType), "content-type");
+/// assert_eq!(Header::from("ETag".to_string()), Header::Etag);
+/// ```
+///
+/// A `prefix` shared by the generated strings can be given once for the whole
+/// enum. The prefixed identifiers are then converted to
+/// `SCREAMING_SNAKE_CASE`, unless `rename_all` picks another style. The prefix
+/// is split into words at `_`, `-` and changes of case, so with
+/// `rename_all = "kebab-case"` the variants below would become
+/// `"http-method-get"` and `"http-method-post"`:
+///
+/// ```
+/// #[enum_other::other(String, prefix = "HTTP_METHOD_")]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum HttpMethod {
+///     Get,  // = "HTTP_METHOD_GET"
+///     Post, // = "HTTP_METHOD_POST"
+/// }
+///
+/// assert_eq!(String::from(HttpMethod::Get), "HTTP_METHOD_GET");
+/// assert_eq!(HttpMethod::from("HTTP_METHOD_POST"), HttpMethod::Post);
+/// ```
+///
+/// Variants can accept additional values with `#[other(alias = ...)]`. Aliases
+/// decode to the variant, but the variant always encodes to its discriminant:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Parity {
+///     None = b'N',
+///     #[other(alias = b'e')]
+///     Even = b'E',
+///     #[other(alias = b'o')]
+///     Odd = b'O',
+/// }
+///
+/// assert_eq!(Parity::from(b'e'), Parity::Even);
+/// assert_eq!(u8::from(Parity::Even), b'E');
+/// ```
+///
+/// Every value may only be used once across all discriminants and aliases,
+/// except that variants can share a discriminant when one of them is marked
+/// `#[other(primary)]`. The value then decodes to the primary variant, while
+/// every variant sharing it still encodes to it:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     #[deprecated = "renamed to `Https`"]
+///     SvcbHttps = 65,
+///     #[other(primary)]
+///     Https = 65,
+/// }
+///
+/// assert_eq!(RecordType::from(65), RecordType::Https);
+/// #[allow(deprecated)]
+/// let legacy = RecordType::SvcbHttps;
+/// assert_eq!(u16::from(legacy), 65);
+/// ```
+///
+/// String enums passing `case_insensitive` decode their strings ignoring ASCII
+/// case, while still encoding each variant as written. No two strings may then
+/// differ only in case:
+///
+/// ```
+/// #[enum_other::other(String, case_insensitive)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Connection {
+///     KeepAlive = "keep-alive",
+///     Close = "close",
+/// }
+///
+/// assert_eq!(Connection::from("Keep-Alive".to_string()), Connection::KeepAlive);
+/// assert_eq!(String::from(Connection::KeepAlive), "keep-alive");
+/// ```
+///
+/// A variant marked `#[other(prefix = "...")]` holds any string starting with
+/// the prefix, and encodes to the string it holds. Exact strings are matched
+/// before any prefix, and longer prefixes before shorter ones:
+///
+/// ```
+/// #[enum_other::other(String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Mechanism {
+///     Plain = "PLAIN",
+///     #[other(prefix = "SCRAM-")]
+///     Scram,
+/// }
+///
+/// assert_eq!(
+///     Mechanism::from("SCRAM-SHA-256".to_string()),
+///     Mechanism::Scram("SCRAM-SHA-256".to_string()),
+/// );
+/// assert_eq!(String::from(Mechanism::Scram("SCRAM-SHA-1".to_string())), "SCRAM-SHA-1");
+/// ```
+///
+/// With the `regex` feature enabled, variants can similarly hold any string
+/// matching a regular expression given with `#[other(pattern = "...")]`. These
+/// are tried in declaration order after any exact string or prefix, and are
+/// compiled once on first use. Only some syntax errors are reported by the
+/// macro, so a pattern the `regex` crate rejects panics on first use, naming
+/// the variant:
+///
+/// ```ignore
+/// #[enum_other::other(String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum MediaType {
+///     Json = "application/json",
+///     #[other(pattern = r"^application/.+\+json$")]
+///     JsonBased,
+/// }
+///
+/// assert_eq!(
+///     MediaType::from("application/ld+json"),
+///     MediaType::JsonBased("application/ld+json".to_string()),
+/// );
+/// ```
+///
+/// With the `clap` feature enabled, string enums passing `clap` implement
+/// `clap::ValueEnum`, listing the variants without a value along with their
+/// aliases. Variants sharing a value are listed once, as the primary one. Any
+/// other value is rejected by clap. The enum must implement `Clone`:
+///
+/// ```ignore
+/// #[enum_other::other(String, clap, rename_all = "lowercase")]
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// pub enum OutputFormat {
+///     Json,
+///     #[other(alias = "yml")]
+///     Yaml,
+/// }
+///
+/// #[derive(clap::Parser)]
+/// struct Cli {
+///     #[arg(long, value_enum)]
+///     format: OutputFormat,
+/// }
+///
+/// let cli = Cli::parse_from(["cli", "--format", "yml"]);
+/// assert_eq!(cli.format, OutputFormat::Yaml);
+/// ```
+///
+/// A function taking `&str` and returning `Cow<str>` can be given with
+/// `normalize` to run strings through before they are matched, and before any
+/// `case_insensitive` comparison. The "other" variant keeps the original
+/// string, unless `store_normalized` is also passed:
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// fn trim(value: &str) -> Cow<'_, str> {
+///     Cow::Borrowed(value.trim())
+/// }
+///
+/// #[enum_other::other(String, normalize = trim)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Command {
+///     Quit = "QUIT",
+/// }
+///
+/// assert_eq!(Command::from(" QUIT\n".to_string()), Command::Quit);
+/// assert_eq!(Command::from(" NOOP".to_string()), Command::Other(" NOOP".to_string()));
+/// ```
+///
+/// With the `serde` feature enabled, string enums passing `serde` implement
+/// `Deserialize`. Known strings are matched without allocating, only unknown
+/// ones being copied into the "other" variant:
+///
+/// ```ignore
+/// #[enum_other::other(String, serde)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Method {
+///     Get = "GET",
+///     Post = "POST",
+/// }
+///
+/// assert_eq!(serde_json::from_str::<Method>(r#""GET""#)?, Method::Get);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// With the `serde_json` feature enabled, integer and string enums passing
+/// `serde_json` get `to_json_value` and `from_json_value`, converting them to
+/// and from a `serde_json::Value` without going through serde. The "other"
+/// variant is converted like the known ones, and `from_json_value` returns
+/// `None` for values that are not of the data type. String enums also need
+/// `from_ref`, as the value is encoded from a reference:
+///
+/// ```ignore
+/// use serde_json::Value;
+///
+/// #[enum_other::other(u16, serde_json)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Port {
+///     Http = 80,
+/// }
+///
+/// assert_eq!(Port::Http.to_json_value(), Value::from(80));
+/// assert_eq!(Port::Other(8080).to_json_value(), Value::from(8080));
+/// assert_eq!(Port::from_json_value(&Value::from(80)), Some(Port::Http));
+/// assert_eq!(Port::from_json_value(&Value::from("80")), None);
+/// ```
+///
+/// With the `phf` feature enabled, string enums passing `phf` look their
+/// values up in a `phf::Map` built at compile time, rather than comparing them
+/// one by one. Values must then be string literals, and cannot be compared
+/// with `case_insensitive`:
+///
+/// ```ignore
+/// #[enum_other::other(String, phf)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum MediaType {
+///     Html = "text/html",
+///     Json = "application/json",
+///     Png = "image/png",
+/// }
+///
+/// assert_eq!(MediaType::from("image/png"), MediaType::Png);
+/// ```
+///
+/// String enums can give a variant the string to use in both directions with
+/// `#[other(rename = "...")]`, in place of its discriminant:
+///
+/// ```
+/// #[enum_other::other(String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Header {
+///     Host = "Host",
+///     #[other(rename = "X-Forwarded-For")]
+///     ForwardedFor,
+/// }
+///
+/// assert_eq!(String::from(Header::ForwardedFor), "X-Forwarded-For");
+/// assert_eq!(Header::from("X-Forwarded-For".to_string()), Header::ForwardedFor);
+/// ```
+///
+/// Binary fields of variable length can be converted to and from `Vec<u8>`
+/// or `Box<[u8]>`, with byte strings as discriminants. Values are matched as
+/// byte slices, and unknown ones are kept as is. Slices also convert, only
+/// being copied if they are kept:
+///
+/// ```
+/// #[enum_other::other(Vec<u8>)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum TlvType {
+///     Hostname = b"\x01\x02",
+///     Port = b"\x01\x04",
+/// }
+///
+/// assert_eq!(TlvType::from(vec![1, 2]), TlvType::Hostname);
+/// assert_eq!(TlvType::from(vec![9]), TlvType::Other(vec![9]));
+/// assert_eq!(Vec::<u8>::from(TlvType::Port), [1, 4]);
+/// assert_eq!(TlvType::from(&[1, 4][..]), TlvType::Port);
+/// ```
+///
+/// Byte arrays such as four-character codes take byte strings of their length
+/// as discriminants, with or without dereferencing them, or arrays of bytes:
+///
+/// ```
+/// #[enum_other::other([u8; 4])]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Codec {
+///     Avc1 = *b"avc1",
+///     Hvc1 = b"hvc1",
+///     Av01 = [0x61, 0x76, 0x30, 0x31],
+/// }
+///
+/// assert_eq!(Codec::from(*b"hvc1"), Codec::Hvc1);
+/// assert_eq!(<[u8; 4]>::from(Codec::Avc1), *b"avc1");
+/// assert_eq!(Codec::from(*b"av01"), Codec::Av01);
+/// ```
+///
+/// Other arrays, nested ones included, take array expressions of their length
+/// as discriminants, which are matched as they are:
+///
+/// ```
+/// #[enum_other::other([u16; 2])]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Version {
+///     V1 = [1, 0],
+///     V2 = [2, 0],
+/// }
+///
+/// assert_eq!(Version::from([2, 0]), Version::V2);
+/// assert_eq!(Version::from([2, 1]), Version::Other([2, 1]));
+/// assert_eq!(<[u16; 2]>::from(Version::V1), [1, 0]);
+/// ```
+///
+/// `f32` and `f64` enums take float discriminants, which every variant needs.
+/// Values are matched by their bits, so a NaN discriminant matches the NaN
+/// with the same bits, while `0.0` and `-0.0` are different values:
+///
+/// ```
+/// #[enum_other::other(f32)]
+/// #[derive(Debug, PartialEq)]
+/// pub enum Sample {
+///     Missing = f32::NAN,
+///     Zero = 0.0,
+/// }
+///
+/// assert_eq!(Sample::from(f32::NAN), Sample::Missing);
+/// assert_eq!(Sample::from(0.0), Sample::Zero);
+/// assert_eq!(Sample::from(-0.0), Sample::Other(-0.0));
+/// assert_eq!(f32::from(Sample::Zero), 0.0);
+/// ```
+///
+/// `NonZero` types such as `NonZeroU16` are matched through `get`, so their
+/// discriminants are integers, counted up from 1 when not given. A
+/// discriminant of zero is an error when compiling:
+///
+/// ```
+/// use std::num::NonZeroU16;
+///
+/// #[enum_other::other(NonZeroU16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Port {
+///     Ssh = 22,
+///     Http = 80,
+/// }
+///
+/// let value = NonZeroU16::new(80).unwrap();
+/// assert_eq!(Port::from(value), Port::Http);
+/// assert_eq!(NonZeroU16::from(Port::Ssh).get(), 22);
+/// ```
+///
+/// `Wrapping` and `Saturating` integers are matched through their field, so
+/// their discriminants are values of the integer they wrap. Other tuple
+/// structs wrapping an integer in a public field can be used the same way by
+/// giving the integer type with `inner`:
+///
+/// ```
+/// use std::num::Wrapping;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub struct Port(pub u16);
+///
+/// #[enum_other::other(Wrapping<u16>)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Checksum {
+///     Empty = 0,
+///     Blank = 0xffff,
+/// }
+///
+/// #[enum_other::other(Port, inner = u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Service {
+///     Ssh = 22,
+/// }
+///
+/// assert_eq!(Checksum::from(Wrapping(0xffff)), Checksum::Blank);
+/// assert_eq!(Wrapping::<u16>::from(Checksum::Empty), Wrapping(0));
+/// assert_eq!(Service::from(Port(22)), Service::Ssh);
+/// assert_eq!(Port::from(Service::Ssh), Port(22));
+/// ```
+///
+/// With `Cow<'static, str>` as the data type, known variants encode to
+/// borrowed strings without allocating, while unknown values are kept as they
+/// were given:
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// #[enum_other::other(Cow<'static, str>)]
+/// #[derive(Debug, PartialEq)]
+/// pub enum Method {
+///     Get = "GET",
+/// }
+///
+/// assert!(matches!(Cow::from(Method::Get), Cow::Borrowed("GET")));
+/// assert_eq!(Method::from(Cow::Owned("GET".to_owned())), Method::Get);
+/// assert_eq!(
+///     Method::from(Cow::Borrowed("PUT")),
+///     Method::Other(Cow::Borrowed("PUT"))
+/// );
+/// ```
+///
+/// `char` enums take character literals as discriminants. As characters do
+/// not count up like integers, every variant needs one:
+///
+/// ```
+/// #[enum_other::other(char)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Flag {
+///     Verbose = 'v',
+///     #[other(alias = 'h')]
+///     Help = '?',
+/// }
+///
+/// assert_eq!(Flag::from('h'), Flag::Help);
+/// assert_eq!(char::from(Flag::Other('x')), 'x');
+/// ```
+///
+/// `u8` enums can also use byte literals, which count up like integers:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Digit {
+///     Zero = b'0',
+///     One,
+/// }
+///
+/// assert_eq!(Digit::from(b'1'), Digit::One);
+/// assert_eq!(u8::from(Digit::Zero), 48);
+/// ```
+///
+/// Discriminants can also refer to constants or be arbitrary constant
+/// expressions. These are compared with `==` instead of being used as a
+/// pattern:
+///
+/// ```
+/// mod protocol {
+///     pub const TCP: u8 = 6;
+///     pub const UDP: u8 = 17;
+/// }
+///
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum IpProtocol {
+///     Icmp = 1,
+///     Tcp = protocol::TCP,
+///     Udp = protocol::UDP,
+/// }
+///
+/// assert_eq!(IpProtocol::from(6), IpProtocol::Tcp);
+/// assert_eq!(u8::from(IpProtocol::Udp), 17);
+/// ```
+///
+/// Discriminants after an arithmetic expression of integer literals like
+/// `1 << 4` are still inferred, but one must be given explicitly after any
+/// other expression.
+///
+/// A range can be used as a discriminant to have a variant hold any value
+/// within it. Ranges must not overlap with each other or with discriminants:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum OptionCode {
+///     Pad = 0,
+///     End = 255,
+///     PrivateUse = 224..=254,
+/// }
+///
+/// assert_eq!(OptionCode::from(230), OptionCode::PrivateUse(230));
+/// assert_eq!(u8::from(OptionCode::PrivateUse(240)), 240);
+/// assert_eq!(OptionCode::from(255), OptionCode::End);
+/// ```
+///
+/// Values outside a range given with `valid` can be told apart from unknown
+/// ones by naming a variant with `invalid` to hold them. Discriminants must
+/// all be within the valid range:
+///
+/// ```
+/// #[enum_other::other(u8, valid = 0..=15, invalid = Invalid)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Opcode {
+///     Query = 0,
+///     Status = 2,
+/// }
+///
+/// assert_eq!(Opcode::from(4), Opcode::Other(4));
+/// assert_eq!(Opcode::from(16), Opcode::Invalid(16));
+/// assert_eq!(u8::from(Opcode::Invalid(16)), 16);
+/// ```
+///
+/// Such variants can also be given with `fallbacks(...)`, which names the
+/// variant holding the remaining values with `_` in place of the "other" one:
+///
+/// ```
+/// #[enum_other::other(u16, fallbacks(Private = 0xff00..=0xffff, Unassigned = _))]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+/// }
+///
+/// assert_eq!(RecordType::from(0xff01), RecordType::Private(0xff01));
+/// assert_eq!(RecordType::from(3), RecordType::Unassigned(3));
+/// ```
+///
+/// Variants marked `#[other(skip)]` are left out of the conversions and may
+/// have fields. Encoding is only implemented if every skipped variant has a
+/// discriminant to encode as:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Message {
+///     Ping = 1,
+///     #[other(skip)]
+///     Pong { payload: u64 } = 2,
+/// }
+///
+/// assert_eq!(Message::from(2), Message::Other(2));
+/// assert_eq!(u8::from(Message::Pong { payload: 0 }), 2);
+/// ```
+///
+/// Formats where an absent value has a meaning of its own can mark a unit
+/// variant `#[other(sentinel)]`. `None` then converts to it through
+/// `From<Option<T>>`, or `TryFrom` with `strict`, while `Some` goes through the
+/// data type. If the enum can be encoded, `From<Enum>` for `Option<T>` turns
+/// the sentinel back into `None`. The sentinel may also have a value of its
+/// own, or be skipped to only stand for `None`:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Compression {
+///     #[other(sentinel)]
+///     None = 0,
+///     Deflate = 1,
+/// }
+///
+/// assert_eq!(Compression::from(None), Compression::None);
+/// assert_eq!(Compression::from(Some(1)), Compression::Deflate);
+/// assert_eq!(Option::<u8>::from(Compression::None), None);
+/// assert_eq!(Option::<u8>::from(Compression::Other(7)), Some(7));
+/// ```
+///
+/// Variants without a value can be looked up by name with `from_name`. Passing
+/// `from_name_ci` also generates `from_name_ignore_ascii_case`:
+///
+/// ```
+/// #[enum_other::other(u16, from_name_ci)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     A = 1,
+///     Cname = 5,
+/// }
+///
+/// assert_eq!(RecordType::from_name("Cname"), Some(RecordType::Cname));
+/// assert_eq!(RecordType::from_name("CNAME"), None);
+/// assert_eq!(RecordType::from_name("Other"), None);
+/// assert_eq!(
+///     RecordType::from_name_ignore_ascii_case("CNAME"),
+///     Some(RecordType::Cname),
+/// );
+/// ```
+///
+/// Passing `display` generates a `label` method and a `Display` implementation
+/// using the label given with `#[other(display = "...")]`, or the identifier
+/// of variants without one. The "other" variant has no label and displays its
+/// value instead:
+///
+/// ```
+/// #[enum_other::other(u16, display)]
+/// pub enum RecordKind {
+///     #[other(display = "Mail exchange record")]
+///     Mx = 15,
+///     Txt = 16,
+/// }
+///
+/// assert_eq!(RecordKind::Mx.label(), Some("Mail exchange record"));
+/// assert_eq!(RecordKind::Txt.to_string(), "Txt");
+/// assert_eq!(RecordKind::from(99).label(), None);
+/// assert_eq!(RecordKind::from(99).to_string(), "99");
+/// ```
+///
+/// To count or log unknown values, `on_unknown = path` names a function
+/// called with each value decoded to the "other" variant, taking a `&T`, or a
+/// `&str` for enums converted to and from `String`. It only sees the value, so
+/// the outcome of the conversion stays the same:
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static UNKNOWN: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn count_unknown(_: &u16) {
+///     UNKNOWN.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// #[enum_other::other(u16, on_unknown = count_unknown)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     A = 1,
+/// }
+///
+/// assert_eq!(RecordType::from(1), RecordType::A);
+/// assert_eq!(RecordType::from(99), RecordType::Other(99));
+/// assert_eq!(UNKNOWN.load(Ordering::Relaxed), 1);
+/// ```
+///
+/// Enums only ever fed values from trusted sources can pass `deny_unknown`,
+/// so reaching the "other" variant panics with `unreachable!` in builds with
+/// `debug_assertions`. Release builds still convert to the "other" variant,
+/// and `try_from_known` never panics:
+///
+/// ```
+/// #[enum_other::other(u8, deny_unknown)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Opcode {
+///     Nop = 0x00,
+///     Halt = 0x76,
+/// }
+///
+/// assert_eq!(Opcode::from(0x76), Opcode::Halt);
+/// assert_eq!(Opcode::try_from_known(0x01), None);
+/// if !cfg!(debug_assertions) {
+///     assert_eq!(Opcode::from(0x01), Opcode::Other(0x01));
+/// }
+/// ```
+///
+/// Enums ending up in errors can pass `error` to implement `Error`, so they
+/// can be the source of another error. Unless `display` is also given, this
+/// generates a `Display` showing the name of known variants along with their
+/// value, and only the value for the "other" variant. The enum must implement
+/// `Debug`:
+///
+/// ```
+/// #[enum_other::other(u16, error)]
+/// #[derive(Debug)]
+/// pub enum HttpStatusCode {
+///     NotFound = 404,
+///     Teapot = 418,
+/// }
+///
+/// let error: Box<dyn std::error::Error> = Box::new(HttpStatusCode::Teapot);
+/// assert_eq!(error.to_string(), "Teapot (418)");
+/// assert_eq!(HttpStatusCode::from(299).to_string(), "299");
+/// ```
+///
+/// Flag enums can pass `bitmask` to check that every discriminant is zero or a
+/// power of two, and to get `contains`, `union` and `intersection` methods
+/// along with implementations of `BitOr`, `BitAnd`, `BitXor` and `Not`.
+/// Combinations of flags are held by the "other" variant:
+///
+/// ```
+/// #[enum_other::other(u8, bitmask)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Access {
+///     Read = 1,
+///     Write = 2,
+///     Execute = 4,
+/// }
+///
+/// let access = Access::Read | Access::Write;
+/// assert_eq!(access, Access::Other(3));
+/// assert!(access.contains(Access::Write));
+/// assert_eq!(access & Access::Read, Access::Read);
+/// ```
+///
+/// Codes packed next to flags can pass `mask` to only compare the masked bits
+/// with the discriminants, which must fit within the mask. Known variants
+/// encode to their discriminant, dropping the flags, while the "other"
+/// variant keeps the value as given:
+///
+/// ```
+/// #[enum_other::other(u8, mask = 0xF0)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Packet {
+///     Connect = 0x10,
+///     Publish = 0x30,
+/// }
+///
+/// assert_eq!(Packet::from(0x3b), Packet::Publish);
+/// assert_eq!(u8::from(Packet::Publish), 0x30);
+/// assert_eq!(Packet::from(0xe3), Packet::Other(0xe3));
+/// ```
+///
+/// Enums naming the positions of a slice can pass `indexable` to implement
+/// `Index` and `IndexMut` for slices and vectors. This requires a `usize`
+/// enum whose discriminants count up from 0 without gaps. The "other" variant
+/// indexes by its value:
+///
+/// ```
+/// #[enum_other::other(usize, indexable)]
+/// pub enum Register {
+///     Rax,
+///     Rbx,
+///     Rcx,
+/// }
+///
+/// let mut registers = [7, 0, 0, 0];
+/// registers[Register::Rcx] = registers[Register::Rax];
+/// assert_eq!(registers[Register::Other(3)], 0);
+/// assert_eq!(registers, [7, 0, 7, 0]);
+/// ```
+///
+/// Attributes listed in `#[other_impl_attr(...)]` are added to every generated
+/// impl. It must be placed below the macro attribute:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[other_impl_attr(allow(clippy::match_same_arms), cfg(not(feature = "raw")))]
+/// pub enum Opcode {
+///     Query = 0,
+///     Status = 2,
+/// }
+/// ```
+///
+/// Similarly, attributes listed in `#[other_attr(...)]` are added to the
+/// "other" variant. Generated code using the variant shares any `cfg` given
+/// here:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[other_attr(doc = "Holds an unrecognized wire value.", deprecated)]
+/// pub enum Cipher {
+///     Aes128 = 1,
+///     Aes256 = 2,
+/// }
+/// ```
+///
+/// To keep users from constructing the "other" variant for values that have
+/// a variant of their own, `deprecate_other` marks it as deprecated, pointing
+/// at the `From` conversion instead, and `hide_other` hides it from the docs:
+///
+/// ```
+/// #[enum_other::other(u8, deprecate_other, hide_other)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Cipher {
+///     Aes128 = 1,
+///     Aes256 = 2,
+/// }
+///
+/// assert_eq!(Cipher::from(7).into_other(), Some(7));
+/// ```
+///
+/// Other attributes on the variants are kept. Generated code naming a
+/// `#[deprecated]` variant allows it, so only code using the variant is
+/// warned:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// pub enum Compression {
+///     None = 0,
+///     #[deprecated = "use `Zstd` instead"]
+///     Lz4 = 1,
+///     Zstd = 2,
+/// }
+///
+/// assert!(matches!(Compression::from(2), Compression::Zstd));
+/// ```
+///
+/// They can also be given inline through `attrs(...)`:
+///
+/// ```
+/// #[enum_other::other(u16, Unknown, attrs(doc = "Unrecognized record type", doc(hidden)))]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+/// }
+/// ```
+///
+/// For primitive integer types, a `#[repr(...)]` on the enum giving an
+/// integer type keeps the discriminants on the variants, so the tags of the
+/// known variants match their values and `strict` enums can still be cast with
+/// `as`. Passing `repr` adds `#[repr(T)]` if no representation is given. The
+/// variants without a discriminant, such as the "other" variant, are given the
+/// tag following the variant before them, with the "other" variant holding its
+/// value after the tag. Discriminants that do not fit the representation, or
+/// tags that clash with a discriminant, are reported:
+///
+/// ```
+/// #[enum_other::other(u8, repr)]
+/// pub enum Kind {
+///     Request = 0x10,
+///     Response = 0x20,
+/// }
+///
+/// #[enum_other::other(u16, strict)]
+/// #[repr(u16)]
+/// pub enum Port {
+///     Http = 80,
+///     Https = 443,
+/// }
+///
+/// assert_eq!(std::mem::size_of::<Kind>(), 2);
+/// assert_eq!(Port::Https as u16, 443);
+/// ```
+///
+/// Any `#[non_exhaustive]` on the enum is kept as is, and passing
+/// `non_exhaustive` adds one. While every value has a variant to go to, adding
+/// known variants is still a breaking change without it, as code matching on
+/// the enum would no longer compile. It may not be given to the "other"
+/// variant through `other_attr`, as unknown values could then only be
+/// constructed from within the defining crate.
+///
+/// Passing `doc_table` documents the conversions with a table of the values
+/// going to each variant, including aliases, ranges and the "other" variant,
+/// so that the mapping shows up in rustdoc without reading the enum:
+///
+/// ```
+/// #[enum_other::other(u16, doc_table)]
+/// pub enum Port {
+///     #[other(alias = 8080)]
+///     Http = 80,
+///     Https = 443,
+/// }
+///
+/// assert!(matches!(Port::from(8080), Port::Http));
+/// ```
+///
+/// Passing `doc_aliases` makes each variant show up when searching rustdoc for
+/// its discriminant, including inferred ones. Integers are given both in
+/// decimal and in hex, and aliases the variant already has are not repeated:
+///
+/// ```
+/// #[enum_other::other(u16, doc_aliases)]
+/// pub enum RecordType {
+///     A = 1,
+///     // Found by searching for `28` or `0x1c`.
+///     Aaaa = 28,
+/// }
+/// ```
+///
+/// Conversions written by hand can be left out with one of these flags:
+///
+/// - `from_only` leaves out `From<Enum>` and `From<&Enum>` for the data type.
+/// - `into_only` leaves out `From<T>`, `TryFrom<T>` with `strict`, and
+///   `From<&str>` for the enum.
+/// - `no_impls` leaves out all of the above.
+///
+/// Everything else is still generated, and relies on the hand-written
+/// conversions where it needs them:
+///
+/// ```
+/// #[enum_other::other(u8, from_only)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Shape {
+///     Circle = 1,
+///     Square = 2,
+/// }
+///
+/// impl From<Shape> for u8 {
+///     fn from(value: Shape) -> Self {
+///         match value {
+///             Shape::Circle => 1,
+///             Shape::Square => 2,
+///             Shape::Other(value) => value.min(100),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Shape::from(2), Shape::Square);
+/// assert_eq!(u8::from(Shape::Other(200)), 100);
+/// ```
+///
+/// Types known to be `Copy` can be converted from a reference to the enum.
+/// Passing `from_ref` does the same for other types by cloning the enum. The
+/// strings of `String` enums are borrowed instead, which also converts a
+/// reference to the enum into a `&str` living as long:
+///
+/// ```
+/// #[enum_other::other(String, from_ref)]
+/// pub enum Encoding {
+///     Gzip = "gzip",
+///     Brotli = "br",
+/// }
+///
+/// let encoding = Encoding::Brotli;
+/// assert_eq!(String::from(&encoding), "br");
+///
+/// let encoding = Encoding::from("zstd");
+/// let name: &str = (&encoding).into();
+/// assert_eq!(name, "zstd");
+/// ```
+///
+/// The enum also gains `into_other` and `try_into_known` methods to get at the
+/// value of the "other" variant:
+///
+/// ```
+/// #[enum_other::other(u8)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Compression {
+///     None = 0,
+///     Deflate = 1,
+/// }
+///
+/// assert_eq!(Compression::from(64).into_other(), Some(64));
+/// assert_eq!(Compression::Deflate.into_other(), None);
+///
+/// assert_eq!(Compression::from(0).try_into_known(), Ok(Compression::None));
+/// assert_eq!(Compression::from(64).try_into_known(), Err(64));
+/// ```
+///
+/// Values can also be checked without constructing the "other" variant using
+/// `try_from_known`:
+///
+/// ```
+/// #[enum_other::other(String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Scheme {
+///     Http = "http",
+///     Https = "https",
+/// }
+///
+/// assert_eq!(Scheme::try_from_known("https".to_string()), Some(Scheme::Https));
+/// assert_eq!(Scheme::try_from_known("ftp".to_string()), None);
+/// ```
+///
+/// To only check whether a value is known, `is_known_value` takes the value
+/// without constructing the enum, and is a `const fn` for primitive integer
+/// types. Enums converted to and from `String` get `is_known_str` instead,
+/// which takes a `&str`:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// pub enum Port {
+///     Http = 80,
+///     Https = 443,
+/// }
+///
+/// const _: () = assert!(Port::is_known_value(443));
+/// assert!(!Port::is_known_value(8080));
+/// ```
+///
+/// The discriminants of the variants are also listed in `DISCRIMINANTS`, in
+/// the order they are declared. Aliases, ranges and skipped variants are left
+/// out, and strings and bytes are listed as `&str` and `&[u8]`:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// pub enum Port {
+///     Http = 80,
+///     Https = 443,
+/// }
+///
+/// assert_eq!(Port::DISCRIMINANTS, [80, 443]);
+/// ```
+///
+/// Variants added in later versions of a protocol can be given the version
+/// they were introduced in with `#[other(since = ...)]`. This generates
+/// `from_with_version`, which takes the version of the peer as a `u32` and
+/// holds values of later variants in the "other" variant instead. `From`
+/// still decodes every variant, and encoding ignores versions:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Command {
+///     Hello = 1,
+///     #[other(since = 2)]
+///     Resume = 3,
+/// }
+///
+/// assert_eq!(Command::from_with_version(3, 1), Command::Other(3));
+/// assert_eq!(Command::from_with_version(3, 2), Command::Resume);
+/// assert_eq!(Command::from(3), Command::Resume);
+/// ```
+///
+/// Passing `strict` leaves out the "other" variant entirely. Decoding is then
+/// implemented through `TryFrom`, which hands back the original value for
+/// anything unrecognized:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// #[enum_other::other(String, strict)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Method {
+///     Get = "GET",
+///     Post = "POST",
+/// }
+///
+/// assert_eq!(Method::try_from("GET".to_string()), Ok(Method::Get));
+/// assert_eq!(Method::try_from("BREW".to_string()), Err("BREW".to_string()));
+/// assert_eq!(String::from(Method::Post), "POST");
+/// ```
+///
+/// Enums covering every value of an integer type of at most 16 bits can pass
+/// `exhaustive` instead. These are `strict` enums whose decoding cannot fail,
+/// so they convert through `From` in both directions. The macro checks that
+/// every value decodes to a variant, listing some that do not otherwise:
+///
+/// ```
+/// #[enum_other::other(i8, exhaustive)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Sign {
+///     Negative = ..0,
+///     Zero = 0,
+///     Positive = 1..,
+/// }
+///
+/// assert_eq!(Sign::from(-5), Sign::Negative(-5));
+/// assert_eq!(i8::from(Sign::Zero), 0);
+/// ```
+///
+/// Two enums over the same data type can be converted into each other with
+/// `convert_with`, which goes through the data type in both directions. Known
+/// values missing from one enum end up in its "other" variant:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordTypeV1 {
+///     A = 1,
+///     Md = 3,
+/// }
+///
+/// #[enum_other::other(u16, convert_with = RecordTypeV1)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordTypeV2 {
+///     A = 1,
+///     Aaaa = 28,
+/// }
+///
+/// assert_eq!(RecordTypeV2::from(RecordTypeV1::A), RecordTypeV2::A);
+/// assert_eq!(RecordTypeV2::from(RecordTypeV1::Md), RecordTypeV2::Other(3));
+/// assert_eq!(RecordTypeV1::from(RecordTypeV2::Other(3)), RecordTypeV1::Md);
+/// ```
+///
+/// Codebases converting through a trait of their own can have it implemented
+/// with `impl_trait(path = Trait, encode = method, decode = method)`, giving
+/// either method or both. They go through the conversions, so `encode` takes
+/// `&self` and returns the data type, and `decode` takes the data type and
+/// returns `Self`, which `strict` enums cannot do. A trait with other
+/// signatures is reported as a type error:
+///
+/// ```
+/// pub trait WireCodec {
+///     fn encode(&self) -> u16;
+///     fn decode(value: u16) -> Self;
+/// }
+///
+/// #[enum_other::other(u16, impl_trait(path = WireCodec, encode = encode, decode = decode))]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+/// }
+///
+/// assert_eq!(RecordType::Ns.encode(), 2);
+/// assert_eq!(RecordType::decode(7), RecordType::Other(7));
+/// ```
+///
+/// For integer types, `known = Name` adds a fieldless companion enum with the
+/// variants that have a discriminant, keeping it so the companion can be cast
+/// with `as` and passed over FFI. It converts into the enum, and from the enum
+/// and the data type through `TryFrom`. Attributes for it, such as derives,
+/// are given with `known_attrs(...)`:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// #[enum_other::other(u16, known = RecordTypeKnown, known_attrs(derive(Debug, PartialEq)))]
+/// #[derive(Debug, PartialEq)]
+/// pub enum RecordType {
+///     A = 1,
+///     Cname = 5,
+/// }
+///
+/// assert_eq!(RecordTypeKnown::Cname as u16, 5);
+/// assert_eq!(RecordType::from(RecordTypeKnown::A), RecordType::A);
+/// assert_eq!(RecordTypeKnown::try_from(RecordType::Other(7)), Err(RecordType::Other(7)));
+/// assert_eq!(RecordTypeKnown::try_from(5), Ok(RecordTypeKnown::Cname));
+/// ```
+///
+/// The generated inherent methods, such as `from_name` and `into_other`, and
+/// companion types like the one from `known` have the visibility of the enum.
+/// Another one is given with `vis = ...`, for example to keep them out of the
+/// public API with `vis = pub(crate)`. Trait implementations cannot have a
+/// visibility, so they are not affected:
+///
+/// ```
+/// mod protocol {
+///     #[enum_other::other(u16, vis = pub(crate))]
+///     #[derive(Debug, PartialEq, Eq)]
+///     pub enum RecordType {
+///         A = 1,
+///     }
+/// }
+///
+/// use protocol::RecordType;
+///
+/// assert_eq!(RecordType::from_name("A"), Some(RecordType::A));
+/// assert_eq!(RecordType::from(1), RecordType::A);
+/// ```
+///
+/// Enums that must stay fieldless, for example because they are generated
+/// for FFI, can be left exactly as written with `wrapper = Name`. The macro
+/// then generates `enum Name { Known(Enum), Other(T) }`, with the conversions
+/// to and from the data type on it and conversions between it and the enum.
+/// The variants are cast to the data type, so they cannot hold data, and only
+/// `alias` and `skip` can be given to them. Attributes for the wrapper are
+/// given with `wrapper_attrs(...)`, and no other options can be combined with
+/// it:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// #[enum_other::other(u16, wrapper = MaybeRecordType, wrapper_attrs(derive(Debug, PartialEq)))]
+/// #[derive(Debug, PartialEq)]
+/// #[repr(u16)]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+/// }
+///
+/// assert_eq!(RecordType::Ns as u16, 2);
+/// assert_eq!(MaybeRecordType::from(2), MaybeRecordType::Known(RecordType::Ns));
+/// assert_eq!(MaybeRecordType::from(9), MaybeRecordType::Other(9));
+/// assert_eq!(u16::from(MaybeRecordType::Known(RecordType::A)), 1);
+/// assert_eq!(RecordType::try_from(MaybeRecordType::Other(9)), Err(MaybeRecordType::Other(9)));
+/// ```
+///
+/// The "other" variant can instead hold another enum over the same data type
+/// given with `fallback_into`, for code spaces where unknown values are
+/// structured further. Both enums must convert to and from the data type:
+///
+/// ```
+/// #[enum_other::other(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum VendorOption {
+///     Trace = 0xfdea,
+/// }
+///
+/// #[enum_other::other(u16, fallback_into = VendorOption)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum EdnsOption {
+///     Nsid = 3,
+///     Cookie = 10,
+/// }
+///
+/// assert_eq!(EdnsOption::from(0xfdea), EdnsOption::Other(VendorOption::Trace));
+/// assert_eq!(EdnsOption::from(99), EdnsOption::Other(VendorOption::Other(99)));
+/// assert_eq!(u16::from(EdnsOption::Other(VendorOption::Trace)), 0xfdea);
+/// ```
+///
+/// To keep the enum small or its clones cheap, the "other" variant can hold
+/// the value as another type given with `store`, such as `Box<str>` or
+/// `Arc<str>` for strings. The conversions still take and return the data
+/// type, and the stored type must convert from it. Strings are copied back out
+/// through `AsRef<str>`, while other types must also convert back:
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// #[enum_other::other(String, store = Arc<str>)]
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// pub enum Header {
+///     Host = "host",
+/// }
+///
+/// assert_eq!(Header::from("x-trace"), Header::Other(Arc::from("x-trace")));
+/// assert_eq!(String::from(Header::from("x-trace")), "x-trace");
+/// ```
+///
+/// String enums also convert from and into `Box<str>`, for values kept boxed
+/// elsewhere:
+///
+/// ```
+/// #[enum_other::other(String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Scheme {
+///     Http = "http",
+/// }
+///
+/// assert_eq!(Scheme::from(Box::<str>::from("http")), Scheme::Http);
+/// assert_eq!(Box::<str>::from(Scheme::Http), Box::from("http"));
+/// ```
+///
+/// Text arriving in pieces can be collected with `writer = Name`, which
+/// generates a type implementing `fmt::Write`. Its `finish` method converts the
+/// text written so far, returning a `Result` for `strict` enums:
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// #[enum_other::other(String, writer = StatusWriter)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Status {
+///     Error = "ERROR",
+/// }
+///
+/// let mut writer = StatusWriter::default();
+/// writer.write_str("ERR").unwrap();
+/// writer.write_str("OR").unwrap();
+/// assert_eq!(writer.finish(), Status::Error);
+/// ```
+///
+/// Large data types can pass `boxed` to hold unknown values in a `Box`, so
+/// the rare "other" variant does not make every value of the enum as large.
+/// The conversions box and unbox the value, and tuples are held as a whole:
+///
+/// ```
+/// #[enum_other::other((u64, u64, u64, u64), boxed)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Fingerprint {
+///     Root = (1, 2, 3, 4),
+/// }
+///
+/// assert_eq!(Fingerprint::from((0, 0, 0, 0)), Fingerprint::Other(Box::new((0, 0, 0, 0))));
+/// assert_eq!(std::mem::size_of::<Fingerprint>(), std::mem::size_of::<usize>());
+/// ```
+///
+/// The macro can only be applied once, but further data types can be mapped
+/// with `#[other_mapping(key: Type)]` below it, with each variant giving its
+/// value as `#[other(key = ...)]`. As only the data type given to the macro
+/// has the "other" variant, these convert through `TryFrom` in both
+/// directions, failing for values and variants outside the mapping:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// #[enum_other::other(u16)]
+/// #[other_mapping(text: String)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     #[other(text = "A")]
+///     A = 1,
+///     #[other(text = "NS")]
+///     Ns = 2,
+/// }
+///
+/// assert_eq!(RecordType::try_from("NS".to_string()), Ok(RecordType::Ns));
+/// assert_eq!(String::try_from(RecordType::A), Ok("A".to_string()));
+/// assert_eq!(String::try_from(RecordType::Other(5)), Err(RecordType::Other(5)));
+/// ```
+#[proc_macro_attribute]
+pub fn other(args: TokenStream, item: TokenStream) -> TokenStream {
+    if let Err(e) = check_variant_visibility.parse(item.clone()) {
+        return e.into_compile_error().into();
+    }
+    let mut item = parse_macro_input!(item as ItemEnum);
+    let args = parse_macro_input!(args as Args);
+
+    expand(args, &mut item)
+        .map(|impls| quote! { #item #impls })
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives the same conversions as [`macro@other`] for an enum that declares
+/// the "other" variant itself, as a derive cannot change the enum.
+///
+/// The arguments are given in an `#[other(...)]` attribute on the enum, with
+/// the data type as `type` and a different identifier for the "other" variant
+/// as `ident`. Options that would change the enum, such as `repr`, `position`
+/// and `attrs(...)`, are not available. Enums holding data need a `#[repr]`
+/// to keep their discriminants:
+///
+/// ```
+/// use enum_other::EnumOther;
+///
+/// #[derive(Debug, PartialEq, Eq, EnumOther)]
+/// #[other(type = u16, ident = Unknown)]
+/// #[repr(u16)]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+///     Unknown(u16),
+/// }
+///
+/// assert_eq!(RecordType::from(2), RecordType::Ns);
+/// assert_eq!(u16::from(RecordType::Unknown(99)), 99);
+/// ```
+#[proc_macro_derive(EnumOther, attributes(other, other_impl_attr, other_mapping))]
+pub fn derive_enum_other(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemEnum);
+
+    derive::expand(item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Asserts that each of the values converts into the enum and back into
+/// itself, catching mistakes in the discriminants of known variants.
+///
+/// The data type converted back into is inferred from the values, or can be
+/// given after the enum where they are converted, such as for strings:
+///
+/// ```
+/// use enum_other::{assert_round_trip, other};
+///
+/// #[other(u16)]
+/// #[derive(Debug)]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+///     Cname = 5,
+/// }
+///
+/// #[other(String)]
+/// #[derive(Debug)]
+/// pub enum Method {
+///     Get = "GET",
+///     #[other(alias = "post")]
+///     Post = "POST",
+/// }
+///
+/// assert_round_trip!(RecordType, [1, 2, 5, 300]);
+/// assert_round_trip!(Method: String, ["GET", "POST", "PATCH"]);
+/// ```
+///
+/// Conversions are not `const`, so the assertions run where the macro is
+/// used, typically in a test. Each value is evaluated once and converted from
+/// a clone, so values must implement `Clone`. Aliases are decoded but not encoded, so they do
+/// not round-trip:
+///
+/// ```should_panic
+/// # #[enum_other::other(String)]
+/// # pub enum Method {
+/// #     #[other(alias = "post")]
+/// #     Post = "POST",
+/// # }
+/// enum_other::assert_round_trip!(Method: String, ["post"]);
+/// ```
+#[proc_macro]
+pub fn assert_round_trip(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as round_trip::RoundTrip)
+        .expand()
+        .into()
+}
+
+/// Asserts that every value of an integer type converts into the enum and
+/// back into itself, catching discriminants that do not have the value they
+/// appear to have:
+///
+/// ```
+/// use enum_other::{assert_coverage, other};
+///
+/// #[other(u8)]
+/// #[derive(Debug)]
+/// pub enum Opcode {
+///     Query = 0,
+///     IQuery = 1,
+///     Status = 2,
+///     Notify = 4,
+/// }
+///
+/// assert_coverage!(Opcode, u8);
+/// ```
+///
+/// Every value of 8 and 16 bit types is checked. Wider types cannot be
+/// iterated over in a test, so only a sample is checked: the values up to
+/// `0xffff`, down to `-0x8000` for signed types, those near the bounds of the
+/// type, and the powers of two with the values right below them. Aliases are
+/// decoded but not encoded, so an enum with any does not pass:
+///
+/// ```should_panic
+/// # #[enum_other::other(u16)]
+/// # pub enum Opcode {
+/// #     #[other(alias = 3)]
+/// #     Status = 2,
+/// # }
+/// enum_other::assert_coverage!(Opcode, u16);
+/// ```
+#[proc_macro]
+pub fn assert_coverage(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as round_trip::Coverage)
+        .expand()
+        .into()
+}
+
+/// Forwards the conversions of an enum to a struct wrapping it, for each of
+/// the data types given. The struct also converts to and from the enum:
+///
+/// ```
+/// use enum_other::{other, other_newtype};
+///
+/// #[other(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum RecordType {
+///     A = 1,
+///     Ns = 2,
+/// }
+///
+/// #[other_newtype(u16)]
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub struct QueryType(RecordType);
+///
+/// assert_eq!(QueryType::from(2), QueryType(RecordType::Ns));
+/// assert_eq!(u16::from(QueryType(RecordType::A)), 1);
+/// assert_eq!(RecordType::from(QueryType::from(99)), RecordType::Other(99));
+/// ```
+///
+/// The enum must convert with `From` in both directions, so `strict` enums
+/// cannot be wrapped.
+#[proc_macro_attribute]
+pub fn other_newtype(args: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemStruct);
+    let args = parse_macro_input!(args as newtype::NewtypeArgs);
+
+    args.expand(&item)
+        .map(|impls| quote! { #item #impls })
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Reports visibility qualifiers on variants, which `syn` discards but which
+/// the compiler would reject.
+fn check_variant_visibility(input: ParseStream) -> Result<()> {
+    input.call(Attribute::parse_outer)?;
+    input.parse::<Visibility>()?;
+    input.parse::<Token![enum]>()?;
+    input.parse::<Ident>()?;
+    input.parse::<Generics>()?;
+    input.parse::<Option<WhereClause>>()?;
+
+    let content;
+    braced!(content in input);
+    let mut errors = Errors::default();
+    while !content.is_empty() {
+        content.call(Attribute::parse_outer)?;
+        let vis: Visibility = content.parse()?;
+        if !matches!(vis, Visibility::Inherited) {
+            errors.push(Error::new_spanned(
+                vis,
+                "visibility qualifiers are not permitted on enum variants\n\
+                 help: variants always have the visibility of the enum",
+            ));
+        }
+        content.parse::<Variant>()?;
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    errors.finish()
+}
+
+/// How a single variant is mapped to values of the data type.
+enum Mapping {
+    /// A unit variant encoded as its discriminant, which also accepts aliases.
+    Value {
+        discriminant: Expr,
+        aliases: Vec<Expr>,
+    },
+    /// A variant holding any value within a range expression.
+    Range(Expr),
+    /// A variant holding any string starting with a prefix.
+    Prefix(LitStr),
+    /// A variant holding any string matching a regular expression.
+    Pattern(LitStr),
+    /// A variant that is never decoded, and only encoded if it has a value.
+    Skip(Option<Expr>),
+    /// A variant holding any value outside the range given with `valid`.
+    Invalid(ExprRange),
+}
+
+/// Returns the comparisons of `value` checking it is within `range`, as
+/// `contains` cannot be called in a `const fn`.
+fn range_condition(range: &ExprRange) -> TokenStream2 {
+    let below = match range.limits {
+        RangeLimits::HalfOpen(_) => quote! { < },
+        RangeLimits::Closed(_) => quote! { <= },
+    };
+    let bounds = range
+        .from
+        .iter()
+        .map(|from| quote! { value >= #from })
+        .chain(range.to.iter().map(|to| quote! { value #below #to }))
+        .collect::<Vec<_>>();
+    if bounds.is_empty() {
+        return quote! { true };
+    }
+    quote! { #(#bounds)&&* }
+}
+
+/// Returns the type of every element of `ty`, if it is a tuple of a single
+/// element type.
+fn tuple_element_type(ty: &Type) -> Option<&Type> {
+    let elems = match ty {
+        Type::Tuple(TypeTuple { elems, .. }) => elems,
+        _ => return None,
+    };
+    let first = elems.first()?;
+    let key = quote! { #first }.to_string();
+    elems
+        .iter()
+        .all(|elem| quote! { #elem }.to_string() == key)
+        .then_some(first)
+}
+
+/// Evaluates the bounds of `range` as an inclusive range, if possible.
+fn range_bounds(range: &ExprRange) -> Option<(i128, i128)> {
+    let bound = |expr: &Option<Box<Expr>>, unbounded| match expr {
+        Some(expr) => parse_int_expr(expr).ok().flatten(),
+        None => Some(unbounded),
+    };
+
+    let start = bound(&range.from, i128::MIN)?;
+    let end = match (bound(&range.to, i128::MAX)?, &range.limits, &range.to) {
+        (end, RangeLimits::HalfOpen(_), Some(_)) => end.checked_sub(1)?,
+        (end, _, _) => end,
+    };
+    Some((start, end))
+}
+
+/// Rewrites `item` into the enum with the "other" variant, returning the items
+/// implemented for it.
+fn expand(args: Args, item: &mut ItemEnum) -> Result<File> {
+    let Args {
+        data_type,
+        other_ident,
+        other_field_names,
+        other_attrs: mut arg_attrs,
+        position,
+        fallbacks,
+        invalid,
+        bitmask,
+        mask,
+        from_name_ci,
+        case_insensitive,
+        display,
+        serde,
+        serde_json,
+        clap,
+        phf,
+        repr,
+        non_exhaustive,
+        indexable,
+        into_iter,
+        from_iter,
+        doc_table,
+        doc_aliases,
+        error,
+        deny_unknown,
+        default_other,
+        boxed,
+        wrapping,
+        from_ref,
+        deprecate_other,
+        hide_other,
+        encode,
+        decode,
+        strict,
+        exhaustive,
+        rename_all,
+        name_prefix,
+        convert_with,
+        fallback_into,
+        store,
+        inner,
+        known_enum,
+        writer,
+        impl_trait,
+        arity,
+        from_tuple,
+        vis,
+        known_attrs,
+        wrapper,
+        wrapper_attrs,
+        normalize,
+        on_unknown,
+        store_normalized,
+    } = args;
+    if let Some(wrapper) = &wrapper {
+        let vis = vis.unwrap_or_else(|| item.vis.clone());
+        return wrapper::expand(
+            &data_type,
+            &other_ident,
+            wrapper,
+            &wrapper_attrs,
+            &vis,
+            item,
+        )
+        .and_then(syn::parse2);
+    }
+    let mut errors = Errors::default();
+
+    let impl_attrs = match extract_attrs(&mut item.attrs, "other_impl_attr") {
+        Ok(impl_attrs) => impl_attrs,
+        Err(e) => {
+            errors.push(e);
+            Vec::new()
+        }
+    };
+    let mut other_attrs = match extract_attrs(&mut item.attrs, "other_attr") {
+        Ok(other_attrs) => {
+            arg_attrs.extend(other_attrs);
+            arg_attrs
+        }
+        Err(e) => {
+            errors.push(e);
+            arg_attrs
+        }
+    };
+
+    // The elements of an alias are named through a trait implemented for
+    // tuples, unless the fallback variant declared in the enum gives them.
+    let elements_trait = format_ident!("__{}Elements", item.ident);
+    let tuple_like = matches!(data_type, Type::Tuple(_)) || arity.is_some();
+    let mut other_fields = Punctuated::<Type, Token![,]>::new();
+    // The type held by the fallback variant in place of the data type.
+    let stored_type = store
+        .clone()
+        .or_else(|| boxed.then(|| parse_quote! { Box<#data_type> }));
+    // Boxed tuples are still shown element by element.
+    let boxed_elements = match &data_type {
+        Type::Tuple(TypeTuple { elems, .. }) if boxed => Some(elems.len()),
+        _ if boxed => arity,
+        _ => None,
+    };
+    match (
+        &data_type,
+        fallback_into.as_ref().or(stored_type.as_ref()),
+        arity,
+    ) {
+        (_, Some(inner), _) => other_fields.push_value(inner.clone()),
+        (Type::Tuple(TypeTuple { elems, .. }), None, _) => other_fields = elems.clone(),
+        (_, None, Some(arity)) => {
+            other_fields = (0..arity)
+                .map(|i| -> Type {
+                    let element = format_ident!("T{}", i);
+                    parse_quote! { <#data_type as #elements_trait>::#element }
+                })
+                .collect();
+        }
+        _ => other_fields.push_value(data_type.clone()),
+    };
+    let mut declared_elements = false;
+
+    // A fallback variant declared in the enum is taken out to be checked, and
+    // put back in its place along with its attributes.
+    let mut other_field_names = other_field_names;
+    let mut other_index = None;
+    let mut declared_checks = Vec::new();
+    if !strict {
+        for (i, variant) in mem::take(&mut item.variants).into_iter().enumerate() {
+            if other_index.is_some() || variant.ident != other_ident {
+                item.variants.push(variant);
+                continue;
+            }
+            other_index = Some(i);
+
+            if let Some((_, discriminant)) = &variant.discriminant {
+                errors.push(Error::new_spanned(
+                    discriminant,
+                    "the fallback variant cannot have a discriminant",
+                ));
+            }
+            if variant.fields.len() != other_fields.len() {
+                let tokens: &dyn ToTokens = match &variant.fields {
+                    Fields::Unit => &variant.ident,
+                    fields => fields,
+                };
+                let message = match &data_type {
+                    _ if tuple_like => format!(
+                        "the fallback variant must hold the {} elements of `{}` as separate fields",
+                        other_fields.len(),
+                        quote! { #data_type },
+                    ),
+                    _ => format!(
+                        "the fallback variant must hold a single `{}`",
+                        quote! { #other_fields }
+                    ),
+                };
+                errors.push(Error::new_spanned(tokens, message));
+            } else if arity.is_some() {
+                other_fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| field.ty.clone())
+                    .collect();
+                declared_elements = true;
+            } else {
+                // Left to the compiler, as the same type can be named through
+                // other paths and aliases.
+                for (field, ty) in variant.fields.iter().zip(&other_fields) {
+                    let field_ty = &field.ty;
+                    declared_checks.push(quote_spanned! { field_ty.span()=>
+                        const _: fn(#ty) -> #field_ty = |value| value;
+                    });
+                }
+            }
+            if let Fields::Named(fields) = &variant.fields {
+                if let Some(names) = &other_field_names {
+                    errors.push(Error::new_spanned(
+                        names,
+                        "the fallback variant is declared in the enum, which already names its fields",
+                    ));
+                }
+                other_field_names = Some(
+                    fields
+                        .named
+                        .iter()
+                        .map(|field| field.ident.clone().unwrap())
+                        .collect(),
+                );
+            }
+            other_attrs.splice(0..0, variant.attrs);
+        }
+    }
+
+    if let Some((option, position)) = &position {
+        let len = item.variants.len();
+        match (other_index, position) {
+            (Some(_), _) => errors.push(Error::new(
+                option.span(),
+                "a fallback variant declared in the enum is already in position",
+            )),
+            (None, Position::First) => other_index = Some(0),
+            (None, Position::Last) => (),
+            (None, Position::Index(lit)) => match lit.base10_parse::<usize>() {
+                Ok(index) if index <= len => other_index = Some(index),
+                Ok(index) => errors.push(Error::new_spanned(
+                    lit,
+                    format!(
+                        "position {} is past the end of the {} declared variants",
+                        index, len
+                    ),
+                )),
+                Err(e) => errors.push(e),
+            },
+        }
+    }
+    item.variants.extend(fallbacks);
+    // Every value would be an unknown one, so the data type does just as well.
+    if item.variants.is_empty() {
+        errors.push(Error::new_spanned(
+            &item.ident,
+            format!(
+                "`{}` has no variants besides the fallback variant\n\
+                 help: use `{}` directly, or add the known values as variants",
+                item.ident,
+                quote! { #data_type },
+            ),
+        ));
+    }
+
+    if deprecate_other {
+        let note = format!(
+            "construct `{}` through `From<{}>` instead",
+            other_ident,
+            quote! { #data_type },
+        );
+        other_attrs.push(parse_quote! { #[deprecated(note = #note)] });
+    }
+    if hide_other {
+        other_attrs.push(parse_quote! { #[doc(hidden)] });
+    }
+
+    if let Some(attr) = other_attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("non_exhaustive"))
+    {
+        errors.push(Error::new_spanned(
+            attr,
+            "`non_exhaustive` would stop other crates from constructing the fallback variant\n\
+             help: put `#[non_exhaustive]` on the enum instead",
+        ));
+    }
+    if let (true, Some(attr)) = (strict, other_attrs.first()) {
+        errors.push(Error::new_spanned(
+            attr,
+            "`strict` enums have no fallback variant to add attributes to",
+        ));
+    }
+    if default_other {
+        let marked = item
+            .variants
+            .iter()
+            .filter(|variant| variant.ident != other_ident)
+            .flat_map(|variant| &variant.attrs)
+            .find(|attr| attr.path.is_ident("default"));
+        if let Some(attr) = marked {
+            errors.push(Error::new_spanned(
+                attr,
+                "`default_other` makes the fallback variant the default\n\
+                 help: derive `Default` instead to default to this variant",
+            ));
+        }
+    }
+
+    // Expanding again would see the enum as already rewritten.
+    if let Some(attr) = item
+        .attrs
+        .iter()
+        .find(|attr| matches!(attr.path.segments.last(), Some(segment) if segment.ident == "other"))
+    {
+        errors.push(Error::new_spanned(
+            attr,
+            "the macro can only be applied once to an enum\n\
+             help: add further mappings with `#[other_mapping(key: Type)]` instead",
+        ));
+    }
+    let mut extra_mappings = match ExtraMapping::extract(&mut item.attrs) {
+        Ok(extra_mappings) => extra_mappings,
+        Err(e) => {
+            errors.push(e);
+            Vec::new()
+        }
+    };
+    for mapping in &extra_mappings {
+        let extra_type = &mapping.data_type;
+        if quote! { #extra_type }.to_string() == quote! { #data_type }.to_string() {
+            errors.push(Error::new_spanned(
+                extra_type,
+                "the data type given to the macro cannot be mapped again",
+            ));
+        }
+    }
+    let mapping_keys = extra_mappings
+        .iter()
+        .map(|mapping| mapping.key.clone())
+        .collect::<Vec<_>>();
+
+    let string_type = is_string_type(&data_type);
+    let cow_str_type = is_cow_str_type(&data_type);
+    let bytes_type = is_bytes_type(&data_type);
+    let data_type_is =
+        |name: &str| matches!(&data_type, Type::Path(path) if path.path.is_ident(name));
+    let char_type = data_type_is("char");
+    let float_type = data_type_is("f32") || data_type_is("f64");
+    // Tuple structs wrapping the value, such as `Wrapping<u16>`, are matched
+    // through their field and constructed again with the path of the type.
+    let inner = inner.or_else(|| wrapped_int_type(&data_type).cloned());
+    let wrapper_constructor = match (&inner, &data_type) {
+        (Some(_), Type::Path(TypePath { path, .. })) => {
+            let mut path = path.clone();
+            if let Some(segment) = path.segments.last_mut() {
+                segment.arguments = PathArguments::None;
+            }
+            Some(path)
+        }
+        _ => None,
+    };
+    // The values of the discriminants are those of the wrapped type.
+    let value_type = inner.as_ref().unwrap_or(&data_type);
+    // `NonZeroU16` and the like, or `NonZero<u16>`.
+    let nonzero_type = matches!(
+        &data_type,
+        Type::Path(TypePath { qself: None, path })
+            if path.segments.last().is_some_and(|segment| segment.ident.to_string().starts_with("NonZero"))
+    );
+    let array_len = match &data_type {
+        Type::Array(array) => match &array.len {
             Expr::Lit(ExprLit {
-                lit: Lit::Str(_), ..
-            }) => quote! { ::std::string::String::as_str(&value) },
-            _ => quote! { value },
+                lit: Lit::Int(len), ..
+            }) => len.base10_parse::<usize>().ok(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let byte_array_len = array_len.filter(|_| {
+        matches!(&data_type, Type::Array(array) if matches!(&*array.elem, Type::Path(elem) if elem.path.is_ident("u8")))
+    });
+    if repr && !item.attrs.iter().any(|attr| attr.path.is_ident("repr")) {
+        item.attrs.push(parse_quote! { #[repr(#data_type)] });
+    }
+    if non_exhaustive
+        && !item
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("non_exhaustive"))
+    {
+        item.attrs.push(parse_quote! { #[non_exhaustive] });
+    }
+    let mut mappings = Vec::with_capacity(item.variants.len());
+    let mut labels = Vec::with_capacity(item.variants.len());
+    let mut seen = HashMap::<String, Expr>::new();
+    let mut values = Vec::new();
+    let mut ranges = Vec::new();
+    // Discriminants the macro cannot evaluate are checked against the mask by
+    // the compiler instead.
+    let mask_bits = mask
+        .as_ref()
+        .and_then(|mask| parse_int_expr(mask).ok().flatten());
+    let mut mask_checks = Vec::new();
+    let mut prefixes = HashMap::<String, LitStr>::new();
+    // Zero is not a value of `NonZero` types, so counting starts from one.
+    let mut next_discriminant = Ok(i128::from(nonzero_type));
+    let mut deprecated = Vec::new();
+    let mut sentinel: Option<Ident> = None;
+    let mut introduced = Vec::new();
+    // Every discriminant along with the variants sharing it, in order.
+    let mut shared = Vec::<(String, Vec<_>)>::new();
+    let mut primaries = Vec::new();
+    for variant in &mut item.variants {
+        if variant
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("deprecated"))
+        {
+            deprecated.push(variant.ident.clone());
+        }
+        let mut variant_args = match VariantArgs::extract(&mut variant.attrs, &mapping_keys) {
+            Ok(variant_args) => variant_args,
+            Err(e) => {
+                errors.push(e);
+                VariantArgs::default()
+            }
+        };
+        if variant_args.sentinel {
+            match &sentinel {
+                Some(first) => {
+                    let mut error =
+                        Error::new(variant.ident.span(), "only one variant can be the sentinel");
+                    error.combine(Error::new(first.span(), "first given here"));
+                    errors.push(error);
+                }
+                None => sentinel = Some(variant.ident.clone()),
+            }
+        }
+        for (key, value) in mem::take(&mut variant_args.mapped) {
+            if let Some(mapping) = extra_mappings.iter_mut().find(|mapping| mapping.key == key) {
+                mapping.values.push((variant.ident.clone(), value));
+            }
+        }
+        if variant_args.primary {
+            primaries.push(variant.ident.clone());
+        }
+        match variant_args.since.take() {
+            Some(since) if strict => errors.push(Error::new_spanned(
+                since,
+                "`strict` enums have no fallback variant for values of later versions",
+            )),
+            Some(since) => introduced.push((variant.ident.clone(), since)),
+            None => {}
+        }
+
+        match variant_args.display {
+            Some(label) if !display => errors.push(Error::new_spanned(
+                label,
+                "labels are only used with `display`\n\
+                 help: add `display` to the macro arguments",
+            )),
+            Some(label) => labels.push((variant.ident.clone(), label)),
+            None => labels.push((
+                variant.ident.clone(),
+                LitStr::new(&variant.ident.unraw().to_string(), variant.ident.span()),
+            )),
+        }
+
+        let explicit = variant.discriminant.take().map(|(_, expr)| expr);
+        if let (Some(rename), false) = (&variant_args.rename, string_type || cow_str_type) {
+            errors.push(Error::new_spanned(
+                rename,
+                format!(
+                    "`rename` can only be used on enums converted to and from `String` or `Cow<str>`, not `{}`",
+                    quote! { #data_type },
+                ),
+            ));
+        }
+
+        if variant_args.skip {
+            if let Some(rename) = &variant_args.rename {
+                errors.push(Error::new_spanned(
+                    rename,
+                    "skipped variants cannot be renamed",
+                ));
+            }
+            if let Some(prefix) = &variant_args.prefix {
+                errors.push(Error::new_spanned(
+                    prefix,
+                    "skipped variants cannot have a prefix",
+                ));
+            }
+            if let Some(pattern) = &variant_args.pattern {
+                errors.push(Error::new_spanned(
+                    pattern,
+                    "skipped variants cannot have a pattern",
+                ));
+            }
+            if let Some(alias) = variant_args.aliases.first() {
+                errors.push(Error::new_spanned(
+                    alias,
+                    "skipped variants cannot have aliases",
+                ));
+            }
+            if let Some(range @ Expr::Range(_)) = &explicit {
+                errors.push(Error::new_spanned(
+                    range,
+                    "skipped variants cannot use a range",
+                ));
+            }
+            mappings.push((variant.ident.clone(), Mapping::Skip(explicit)));
+            continue;
+        }
+
+        if !matches!(variant.fields, Fields::Unit) {
+            errors.push(Error::new_spanned(
+                &variant.fields,
+                "variants with fields are not converted\n\
+                 help: add `#[other(skip)]` to leave this variant out of the conversions",
+            ));
+        }
+
+        let capture = match (variant_args.prefix, variant_args.pattern) {
+            (Some(prefix), Some(pattern)) => {
+                errors.push(Error::new_spanned(
+                    pattern,
+                    "variants cannot have both a prefix and a pattern",
+                ));
+                Some(("prefix", Mapping::Prefix(prefix)))
+            }
+            (Some(prefix), None) => Some(("prefix", Mapping::Prefix(prefix))),
+            (None, Some(pattern)) => Some(("pattern", Mapping::Pattern(pattern))),
+            (None, None) => None,
+        };
+        if let Some((kind, mapping)) = capture {
+            let lit = match &mapping {
+                Mapping::Prefix(lit) | Mapping::Pattern(lit) => lit,
+                _ => unreachable!(),
+            };
+            if !string_type {
+                errors.push(Error::new_spanned(
+                    lit,
+                    format!(
+                        "`{}` can only be used on enums converted to and from `String`, not `{}`",
+                        kind,
+                        quote! { #data_type },
+                    ),
+                ));
+            }
+            if let Some(expr) = &explicit {
+                errors.push(Error::new_spanned(
+                    expr,
+                    format!("{} variants cannot have a discriminant", kind),
+                ));
+            }
+            if let Some(alias) = variant_args.aliases.first() {
+                errors.push(Error::new_spanned(
+                    alias,
+                    format!("{} variants cannot have aliases", kind),
+                ));
+            }
+            if let Some(rename) = &variant_args.rename {
+                errors.push(Error::new_spanned(
+                    rename,
+                    format!("{} variants cannot be renamed", kind),
+                ));
+            }
+
+            if let Mapping::Prefix(prefix) = &mapping {
+                let mut key = prefix.value();
+                if case_insensitive {
+                    key.make_ascii_lowercase();
+                }
+                match prefixes.entry(key) {
+                    Entry::Occupied(first) => {
+                        let mut error = Error::new_spanned(prefix, "duplicate prefix");
+                        error.combine(Error::new_spanned(first.get(), "first used here"));
+                        errors.push(error);
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(prefix.clone());
+                    }
+                }
+            } else if !cfg!(feature = "regex") {
+                errors.push(Error::new_spanned(
+                    lit,
+                    "`pattern` requires the `regex` feature of `enum-other` to be enabled",
+                ));
+            } else if let Err(message) = pattern::check(&lit.value()) {
+                errors.push(Error::new_spanned(
+                    lit,
+                    format!("invalid pattern: {}", message),
+                ));
+            }
+
+            variant.fields = Fields::Unnamed(parse_quote! { (#data_type) });
+            mappings.push((variant.ident.clone(), mapping));
+            continue;
+        }
+
+        if let Some(Expr::Range(range)) = &explicit {
+            if nonzero_type {
+                errors.push(Error::new_spanned(
+                    range,
+                    format!(
+                        "range discriminants cannot be used with `{}`, which is only matched through `get`",
+                        quote! { #data_type },
+                    ),
+                ));
+            }
+            if mask.is_some() {
+                errors.push(Error::new_spanned(
+                    range,
+                    "range discriminants cannot be used with `mask`, which only compares the masked bits",
+                ));
+            }
+            if let Some(alias) = variant_args.aliases.first() {
+                errors.push(Error::new_spanned(
+                    alias,
+                    "range discriminants cannot have aliases",
+                ));
+            }
+            if let Some(rename) = &variant_args.rename {
+                errors.push(Error::new_spanned(
+                    rename,
+                    "range discriminants cannot be renamed",
+                ));
+            }
+            match range_bounds(range) {
+                Some((start, end)) => {
+                    check_sign(
+                        &mut errors,
+                        value_type,
+                        start,
+                        range,
+                        "start the range at 0",
+                    );
+                    // The bounds are values of the type themselves, even the
+                    // excluded end.
+                    for bound in range.from.iter().chain(&range.to) {
+                        if let Ok(Some(int)) = parse_int_expr(bound) {
+                            check_bounds(&mut errors, value_type, int, bound);
+                        }
+                    }
+                    ranges.push((start, end, range.clone()));
+                    next_discriminant =
+                        end.checked_add(1).ok_or_else(|| Expr::Range(range.clone()));
+                }
+                None => {
+                    for bound in range.from.iter().chain(&range.to) {
+                        if let Err(e) = parse_int_expr(bound) {
+                            errors.push(e);
+                        }
+                    }
+                    next_discriminant = Err(Expr::Range(range.clone()));
+                }
+            }
+
+            variant.fields = Fields::Unnamed(parse_quote! { (#data_type) });
+            mappings.push((
+                variant.ident.clone(),
+                Mapping::Range(Expr::Range(range.clone())),
+            ));
+            continue;
+        }
+
+        let explicit = match variant_args.rename {
+            Some(rename) => Some(
+                ExprLit {
+                    attrs: Vec::new(),
+                    lit: rename.into(),
+                }
+                .into(),
+            ),
+            None => explicit,
+        };
+
+        let implicit = explicit.is_none();
+        let mut discriminant = match explicit {
+            Some(expr) => {
+                let value = match parse_int_expr(&expr) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        errors.push(e);
+                        None
+                    }
+                };
+                next_discriminant = value.ok_or_else(|| expr.clone());
+                if let Some(int) = value {
+                    check_sign(
+                        &mut errors,
+                        value_type,
+                        int,
+                        &expr,
+                        "use a discriminant >= 0",
+                    );
+                }
+                expr
+            }
+            None if bytes_type || byte_array_len.is_some() => {
+                errors.push(Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "variants of `{}` enums need a byte string {}discriminant",
+                        quote! { #data_type },
+                        if bytes_type { "" } else { "or array " },
+                    ),
+                ));
+                parse_quote! { b"" }
+            }
+            None if array_len.is_some() => {
+                errors.push(Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "variants of `{}` enums need an array discriminant, as arrays do not count up",
+                        quote! { #data_type },
+                    ),
+                ));
+                parse_quote! { [] }
+            }
+            None if char_type => {
+                errors.push(Error::new_spanned(
+                    &variant.ident,
+                    "variants of `char` enums need a character discriminant, as characters do not count up",
+                ));
+                parse_quote! { '\0' }
+            }
+            None if float_type => {
+                errors.push(Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "variants of `{}` enums need a discriminant, as floats do not count up",
+                        quote! { #data_type },
+                    ),
+                ));
+                parse_quote! { 0.0 }
+            }
+            None if string_type || cow_str_type => {
+                let name = variant.ident.unraw().to_string();
+                // Prefixed names follow the convention of C and protobuf
+                // enums unless another style is given, and the style applies
+                // to the prefix as well.
+                let name = match (rename_all, &name_prefix) {
+                    (rule, Some(prefix)) => rule
+                        .unwrap_or(RenameRule::ScreamingSnake)
+                        .apply_prefixed(&prefix.value(), &name),
+                    (Some(rule), None) => rule.apply(&name),
+                    (None, None) => name,
+                };
+                ExprLit {
+                    attrs: Vec::new(),
+                    lit: LitStr::new(&name, variant.ident.span()).into(),
+                }
+                .into()
+            }
+            None => match &next_discriminant {
+                Ok(int) => {
+                    let int = match int_bounds(value_type) {
+                        Some((min, max)) if *int > max && wrapping => {
+                            min + (*int - min).rem_euclid(max - min + 1)
+                        }
+                        Some((min, max)) if *int > max => {
+                            errors.push(Error::new_spanned(
+                                &variant.ident,
+                                format!(
+                                    "discriminant {} overflows `{}`\n\
+                                     help: add an explicit discriminant to this variant, or pass `wrapping` to continue from {}",
+                                    int,
+                                    quote! { #value_type },
+                                    min,
+                                ),
+                            ));
+                            *int
+                        }
+                        _ => *int,
+                    };
+                    check_sign(
+                        &mut errors,
+                        value_type,
+                        int,
+                        &variant.ident,
+                        "add an explicit discriminant >= 0 to this variant",
+                    );
+                    // Errors in the arms matching an inferred discriminant
+                    // point at its variant.
+                    ExprLit {
+                        attrs: Vec::new(),
+                        lit: LitInt::new(&int.to_string(), variant.ident.span()).into(),
+                    }
+                    .into()
+                }
+                Err(previous) => {
+                    let mut error = Error::new_spanned(
+                        &variant.ident,
+                        "cannot infer a discriminant after one the macro cannot evaluate\n\
+                         help: add an explicit discriminant to this variant",
+                    );
+                    let note = match previous {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Int(_), ..
+                        }) => "the macro only counts up to `i128::MAX`",
+                        _ if matches!(parse_int_expr(previous), Ok(Some(_))) => {
+                            "the macro only counts up to `i128::MAX`"
+                        }
+                        _ => "only integer literals and arithmetic on them can be evaluated",
+                    };
+                    error.combine(Error::new_spanned(previous, note));
+                    errors.push(error);
+                    // Zero would also be reported for `NonZero` types.
+                    if nonzero_type {
+                        parse_quote! { 1 }
+                    } else {
+                        parse_quote! { 0 }
+                    }
+                }
+            },
+        };
+
+        // Byte strings stand for the array of their bytes, which they must
+        // fill.
+        if let Some(len) = byte_array_len {
+            // Inferred discriminants are already reported.
+            let values = iter::once(&mut discriminant)
+                .skip(usize::from(implicit))
+                .chain(&mut variant_args.aliases);
+            for value in values {
+                let (lit, deref) = match value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::ByteStr(lit),
+                        ..
+                    }) => (lit.clone(), false),
+                    Expr::Unary(ExprUnary {
+                        op: UnOp::Deref(_),
+                        expr,
+                        ..
+                    }) => match &**expr {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::ByteStr(lit),
+                            ..
+                        }) => (lit.clone(), true),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
+                if lit.value().len() != len {
+                    errors.push(Error::new_spanned(
+                        &lit,
+                        format!(
+                            "byte string of length {} given for `{}`",
+                            lit.value().len(),
+                            quote! { #data_type },
+                        ),
+                    ));
+                }
+                if !deref {
+                    *value = parse_quote_spanned! { lit.span()=> *#lit };
+                }
+            }
+        }
+
+        if array_len.is_some() {
+            let values = iter::once(&discriminant)
+                .skip(usize::from(implicit))
+                .chain(&variant_args.aliases);
+            for value in values {
+                check_array(&mut errors, &data_type, value);
+            }
+        }
+
+        for alias in &variant_args.aliases {
+            match parse_int_expr(alias) {
+                Ok(Some(int)) => {
+                    check_sign(&mut errors, value_type, int, alias, "use an alias >= 0")
+                }
+                Ok(None) => (),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        for (i, value) in iter::once(&discriminant)
+            .chain(&variant_args.aliases)
+            .enumerate()
+        {
+            if let (
+                Expr::Lit(ExprLit {
+                    lit: Lit::Byte(_), ..
+                }),
+                false,
+            ) = (value, data_type_is("u8"))
+            {
+                errors.push(Error::new_spanned(
+                    value,
+                    format!(
+                        "byte literals are `u8`, not `{}`\n\
+                         help: use an integer literal instead",
+                        quote! { #data_type },
+                    ),
+                ));
+            }
+            if let Ok(Some(int)) = parse_int_expr(value) {
+                // Inferred discriminants overflowing the type are reported
+                // above.
+                if i > 0 || !implicit {
+                    check_bounds(&mut errors, value_type, int, value);
+                }
+                if nonzero_type && int == 0 {
+                    let tokens: &dyn ToTokens = match (i, implicit) {
+                        (0, true) => &variant.ident,
+                        _ => value,
+                    };
+                    errors.push(Error::new_spanned(
+                        tokens,
+                        format!(
+                            "discriminant 0 is not a value of `{}`",
+                            quote! { #data_type }
+                        ),
+                    ));
+                }
+                if bitmask && (int < 0 || int & (int - 1) != 0) {
+                    let tokens: &dyn ToTokens = match (i, implicit) {
+                        (0, true) => &variant.ident,
+                        _ => value,
+                    };
+                    errors.push(Error::new_spanned(
+                        tokens,
+                        format!(
+                            "discriminant {} is not a power of two, which `bitmask` requires",
+                            int
+                        ),
+                    ));
+                }
+                if let Some(mask) = mask_bits.filter(|&mask| int & !mask != 0) {
+                    let tokens: &dyn ToTokens = match (i, implicit) {
+                        (0, true) => &variant.ident,
+                        _ => value,
+                    };
+                    errors.push(Error::new_spanned(
+                        tokens,
+                        format!(
+                            "discriminant {:#x} has bits outside the mask {:#x}",
+                            int, mask
+                        ),
+                    ));
+                }
+                values.push((int, value.clone()));
+            } else if let (Some(mask), false) = (&mask, matches!(value, Expr::Range(_))) {
+                let message = format!(
+                    "a discriminant of `{}` has bits outside the mask",
+                    variant.ident.unraw(),
+                );
+                mask_checks.push(quote_spanned! { value.span()=>
+                    const _: () = ::core::assert!((#value) & !(#mask) == 0, #message);
+                });
+            }
+            let key = discriminant_key(value, case_insensitive);
+            let group = shared
+                .iter_mut()
+                .find(|(shared_key, _)| *shared_key == key)
+                .map(|(_, group)| group);
+            match (i, group) {
+                (0, Some(group)) => {
+                    group.push((variant.ident.clone(), variant_args.primary, value.clone()));
+                    continue;
+                }
+                (0, None) if !seen.contains_key(&key) => shared.push((
+                    key.clone(),
+                    vec![(variant.ident.clone(), variant_args.primary, value.clone())],
+                )),
+                _ => {}
+            }
+            match seen.entry(key) {
+                Entry::Occupied(first) => {
+                    let message =
+                        if discriminant_key(first.get(), false) == discriminant_key(value, false) {
+                            "duplicate discriminant value"
+                        } else {
+                            "duplicate discriminant value when ignoring ASCII case"
+                        };
+                    let mut error = Error::new_spanned(value, message);
+                    error.combine(Error::new_spanned(first.get(), "first used here"));
+                    errors.push(error);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(value.clone());
+                }
+            }
+        }
+
+        if let Ok(int) = next_discriminant {
+            next_discriminant = int.checked_add(1).ok_or_else(|| discriminant.clone());
+        }
+        mappings.push((
+            variant.ident.clone(),
+            Mapping::Value {
+                discriminant,
+                aliases: variant_args.aliases,
+            },
+        ));
+    }
+
+    for (i, (start, end, range)) in ranges.iter().enumerate() {
+        for (value, expr) in &values {
+            if (start..=end).contains(&value) {
+                let mut error = Error::new_spanned(expr, "discriminant overlaps a range");
+                error.combine(Error::new_spanned(range, "range declared here"));
+                errors.push(error);
+            }
+        }
+        for (other_start, other_end, other_range) in &ranges[..i] {
+            if start <= other_end && other_start <= end {
+                let mut error = Error::new_spanned(range, "range overlaps another range");
+                error.combine(Error::new_spanned(other_range, "other range declared here"));
+                errors.push(error);
+            }
+        }
+    }
+
+    if let Some((ident, valid)) = invalid {
+        match range_bounds(&valid) {
+            Some((start, end)) => {
+                check_sign(
+                    &mut errors,
+                    value_type,
+                    start,
+                    &valid,
+                    "start the range at 0",
+                );
+                for (value, expr) in &values {
+                    if !(start..=end).contains(value) {
+                        let mut error = Error::new_spanned(expr, "discriminant is not valid");
+                        error.combine(Error::new_spanned(&valid, "valid range given here"));
+                        errors.push(error);
+                    }
+                }
+                for (range_start, range_end, range) in &ranges {
+                    if *range_start < start || *range_end > end {
+                        let mut error = Error::new_spanned(range, "range is not entirely valid");
+                        error.combine(Error::new_spanned(&valid, "valid range given here"));
+                        errors.push(error);
+                    }
+                }
+            }
+            None => errors.push(Error::new_spanned(
+                &valid,
+                "`valid` requires a range the macro can evaluate",
+            )),
+        }
+
+        item.variants.push(parse_quote! { #ident(#data_type) });
+        labels.push((
+            ident.clone(),
+            LitStr::new(&ident.unraw().to_string(), ident.span()),
+        ));
+        mappings.push((ident, Mapping::Invalid(valid)));
+    }
+
+    if let Some(sentinel) = &sentinel {
+        let holds_data = item
+            .variants
+            .iter()
+            .any(|variant| variant.ident == *sentinel && !variant.fields.is_empty())
+            || mappings.iter().any(|(ident, mapping)| {
+                ident == sentinel
+                    && matches!(
+                        mapping,
+                        Mapping::Range(_) | Mapping::Prefix(_) | Mapping::Pattern(_)
+                    )
+            });
+        if holds_data {
+            errors.push(Error::new(
+                sentinel.span(),
+                "the sentinel variant cannot hold data, as `None` has none to give it",
+            ));
+        }
+    }
+
+    for mapping in &extra_mappings {
+        mapping.check(&mut errors);
+        for (ident, value) in &mapping.values {
+            if !matches!(
+                mappings.iter().find(|(other, _)| other == ident),
+                Some((_, Mapping::Value { .. }))
+            ) {
+                errors.push(Error::new_spanned(
+                    value,
+                    format!(
+                        "only variants with a discriminant can be given a `{}`",
+                        mapping.key
+                    ),
+                ));
+            }
+        }
+    }
+
+    // Variants sharing a discriminant need exactly one of them to decode to,
+    // which the others are paired with.
+    let mut secondary = Vec::new();
+    for (_, group) in &shared {
+        let marked = group
+            .iter()
+            .filter(|(_, primary, _)| *primary)
+            .collect::<Vec<_>>();
+        match (&group[..], &marked[..]) {
+            ([_], _) => {}
+            ([(_, _, first), rest @ ..], []) => {
+                for (_, _, value) in rest {
+                    let message =
+                        if discriminant_key(first, false) == discriminant_key(value, false) {
+                            "duplicate discriminant value"
+                        } else {
+                            "duplicate discriminant value when ignoring ASCII case"
+                        };
+                    let mut error = Error::new_spanned(
+                        value,
+                        format!(
+                            "{}\n\
+                             help: mark the variant to decode it to with `#[other(primary)]`",
+                            message
+                        ),
+                    );
+                    error.combine(Error::new_spanned(first, "first used here"));
+                    errors.push(error);
+                }
+            }
+            (_, [(primary, _, _)]) => secondary.extend(
+                group
+                    .iter()
+                    .filter(|(ident, _, _)| ident != primary)
+                    .map(|(ident, _, _)| (ident.clone(), primary.clone())),
+            ),
+            (_, [(first, _, _), rest @ ..]) => {
+                for (ident, _, _) in rest {
+                    let mut error = Error::new(
+                        ident.span(),
+                        "only one of the variants sharing a discriminant can be the primary",
+                    );
+                    error.combine(Error::new(first.span(), "first given here"));
+                    errors.push(error);
+                }
+            }
+            ([], _) => unreachable!("every discriminant has a variant"),
+        }
+    }
+    let is_secondary = |ident: &Ident| secondary.iter().any(|(other, _)| other == ident);
+    for ident in &primaries {
+        if !shared
+            .iter()
+            .any(|(_, group)| group.len() > 1 && group.iter().any(|(other, _, _)| other == ident))
+        {
+            errors.push(Error::new(
+                ident.span(),
+                "`primary` is only needed for variants sharing a discriminant",
+            ));
+        }
+    }
+
+    for (ident, since) in &introduced {
+        if !matches!(
+            mappings.iter().find(|(other, _)| other == ident),
+            Some((_, Mapping::Value { .. } | Mapping::Range(_)))
+        ) {
+            errors.push(Error::new_spanned(
+                since,
+                "only variants with a discriminant or a range can be given a `since` version",
+            ));
+        }
+    }
+
+    if indexable {
+        let mut indices = Vec::new();
+        for (_, mapping) in &mappings {
+            let (bounds, expr) = match mapping {
+                Mapping::Value { discriminant, .. } => (
+                    parse_int_expr(discriminant)
+                        .ok()
+                        .flatten()
+                        .map(|int| (int, int)),
+                    discriminant,
+                ),
+                Mapping::Range(expr @ Expr::Range(range)) => (range_bounds(range), expr),
+                _ => continue,
+            };
+            match bounds {
+                Some((start, end)) => indices.push((start, end, expr)),
+                None => errors.push(Error::new_spanned(
+                    expr,
+                    "`indexable` requires discriminants the macro can evaluate",
+                )),
+            }
+        }
+        indices.sort_by_key(|&(start, _, _)| start);
+
+        // Repeated values are already reported as duplicates or overlaps.
+        let mut expected = 0;
+        for (start, end, expr) in indices {
+            if start > expected {
+                errors.push(Error::new_spanned(
+                    expr,
+                    format!(
+                        "`indexable` requires discriminants to be sequential from 0, expected {}",
+                        expected
+                    ),
+                ));
+            }
+            expected = expected.max(end.wrapping_add(1));
+        }
+    }
+
+    if let (true, Some((min, max))) = (exhaustive, int_bounds(value_type)) {
+        let mut covered = vec![false; (max - min + 1) as usize];
+        let mut cover = |start: i128, end: i128| {
+            for int in start.max(min)..=end.min(max) {
+                covered[(int - min) as usize] = true;
+            }
+        };
+        for (ident, mapping) in &mappings {
+            match mapping {
+                Mapping::Value {
+                    discriminant,
+                    aliases,
+                } => {
+                    for value in iter::once(discriminant).chain(aliases) {
+                        match parse_int_expr(value) {
+                            Ok(Some(int)) => cover(int, int),
+                            _ => errors.push(Error::new_spanned(
+                                value,
+                                "`exhaustive` requires discriminants the macro can evaluate",
+                            )),
+                        }
+                    }
+                }
+                Mapping::Range(Expr::Range(range)) => match range_bounds(range) {
+                    Some((start, end)) => cover(start, end),
+                    None => errors.push(Error::new_spanned(
+                        range,
+                        "`exhaustive` requires ranges the macro can evaluate",
+                    )),
+                },
+                Mapping::Invalid(valid) => {
+                    if let Some((start, end)) = range_bounds(valid) {
+                        cover(min, start - 1);
+                        cover(end + 1, max);
+                    }
+                }
+                Mapping::Skip(_) => {}
+                _ => errors.push(Error::new(
+                    ident.span(),
+                    "`exhaustive` can only check variants with a discriminant or a range",
+                )),
+            }
+        }
+
+        let missing = covered
+            .iter()
+            .enumerate()
+            .filter(|(_, covered)| !**covered)
+            .map(|(i, _)| i as i128 + min)
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let mut listed = missing
+                .iter()
+                .take(5)
+                .map(i128::to_string)
+                .collect::<Vec<_>>();
+            if missing.len() > listed.len() {
+                listed.push(format!("and {} more", missing.len() - listed.len()));
+            }
+            errors.push(Error::new_spanned(
+                &item.ident,
+                format!(
+                    "`exhaustive` requires every `{}` to decode to a variant, but none does for {}",
+                    quote! { #data_type },
+                    listed.join(", "),
+                ),
+            ));
+        }
+    }
+    errors.finish()?;
+
+    // Integers are also searchable in hex, and aliases the variant already
+    // has are not repeated.
+    if doc_aliases {
+        for variant in &mut item.variants {
+            let discriminant = mappings.iter().find_map(|(ident, mapping)| match mapping {
+                Mapping::Value { discriminant, .. } if *ident == variant.ident => {
+                    Some(discriminant)
+                }
+                _ => None,
+            });
+            let names = match discriminant {
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                })) => vec![lit.value()],
+                Some(discriminant) => match parse_int_expr(discriminant) {
+                    Ok(Some(int)) if int > 9 => vec![int.to_string(), format!("{:#x}", int)],
+                    Ok(Some(int)) => vec![int.to_string()],
+                    _ => Vec::new(),
+                },
+                None => Vec::new(),
+            };
+            let existing = existing_doc_aliases(&variant.attrs);
+            let ident = variant.ident.unraw().to_string();
+            for name in names {
+                // Rustdoc rejects aliases repeating the name or with quotes and
+                // surrounding whitespace.
+                if name.is_empty()
+                    || name == ident
+                    || name.trim() != name
+                    || name.contains(['"', '\'', '\n', '\t'])
+                    || existing.contains(&name)
+                {
+                    continue;
+                }
+                variant.attrs.push(parse_quote! { #[doc(alias = #name)] });
+            }
+        }
+    }
+
+    let is_string = string_type
+        || cow_str_type
+        || mappings.iter().any(|(_, mapping)| {
+            matches!(
+                mapping,
+                Mapping::Value {
+                    discriminant: Expr::Lit(ExprLit {
+                        lit: Lit::Str(_),
+                        ..
+                    }),
+                    ..
+                }
+            )
+        });
+
+    let (data_type_match, convert_discriminant) = if normalize.is_some() {
+        (
+            quote! { ::core::convert::AsRef::<str>::as_ref(&normalized) },
+            quote! { ::std::string::ToString::to_string },
+        )
+    } else if cow_str_type {
+        (
+            quote! { ::core::convert::AsRef::<str>::as_ref(&value) },
+            quote! { ::std::borrow::Cow::Borrowed },
+        )
+    } else if is_string {
+        (
+            quote! { ::core::convert::AsRef::<str>::as_ref(&value) },
+            quote! { ::std::string::ToString::to_string },
+        )
+    } else if bytes_type {
+        (
+            quote! { ::core::convert::AsRef::<[::core::primitive::u8]>::as_ref(&value) },
+            quote! { <#data_type as ::core::convert::From<&[::core::primitive::u8]>>::from },
+        )
+    } else if let Some(mask) = &mask {
+        (quote! { (value & (#mask)) }, quote! {})
+    } else if nonzero_type {
+        (quote! { <#data_type>::get(value) }, quote! {})
+    } else if let Some(constructor) = &wrapper_constructor {
+        (quote! { value.0 }, quote! { #constructor })
+    } else {
+        (quote! { value }, quote! {})
+    };
+
+    // Discriminants of `NonZero` types are wrapped in a constant, so that a
+    // zero the macro cannot evaluate is still caught when compiling.
+    let encode_value = |value: &Expr| {
+        if nonzero_type {
+            quote_spanned! { value.span()=>
+                {
+                    const VALUE: #data_type = match <#data_type>::new(#value) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => ::core::panic!(
+                            ::core::concat!("discriminant `", ::core::stringify!(#value), "` is zero"),
+                        ),
+                    };
+                    VALUE
+                }
+            }
+        } else {
+            quote! { #convert_discriminant(#value) }
+        }
+    };
+
+    let enum_ident = item.ident.clone();
+    let vis = vis.unwrap_or_else(|| item.vis.clone());
+
+    // The normalized string borrows the input, so it must be dropped before
+    // the input can be moved out.
+    let (normalize_value, release_value) = match &normalize {
+        Some(normalize) => (
+            Some(quote! {
+                let normalized = #normalize(::core::convert::AsRef::<str>::as_ref(&value));
+            }),
+            (!store_normalized).then(|| quote! { ::core::mem::drop(normalized); }),
+        ),
+        None => (None, None),
+    };
+    let stored_value = if store_normalized {
+        quote! { ::std::borrow::Cow::into_owned(normalized) }
+    } else {
+        quote! { value }
+    };
+
+    // Arms naming a deprecated variant must not trip its deprecation, which
+    // is only meant for the code using the variant.
+    let allow = |ident: &Ident| {
+        deprecated
+            .contains(ident)
+            .then(|| quote! { #[allow(deprecated)] })
+    };
+    // Only `strict` enums leaving values out decode through `TryFrom`.
+    let fallible = strict && !exhaustive;
+    let known = |variant: TokenStream2| {
+        if fallible {
+            quote! { ::core::result::Result::Ok(#variant) }
+        } else {
+            variant
+        }
+    };
+
+    let mut encode_arms = Vec::with_capacity(mappings.len());
+    let mut decode_arms = Vec::with_capacity(mappings.len());
+    let mut range_arms = Vec::new();
+    let mut known_checks = Vec::new();
+    let mut since_arms = Vec::new();
+    let mut prefix_variants = Vec::new();
+    let mut pattern_variants = Vec::new();
+    let mut named_variants = Vec::with_capacity(mappings.len());
+    let mut named_allows = Vec::with_capacity(mappings.len());
+    let mut phf_entries = Vec::new();
+    let mut possible_values = Vec::with_capacity(mappings.len());
+    let mut listed_variants = Vec::with_capacity(mappings.len());
+    let mut unencodable = None;
+    for (ident, mapping) in &mappings {
+        let allow = allow(ident);
+        let since = introduced
+            .iter()
+            .find(|(other, _)| other == ident)
+            .map(|(_, since)| since);
+        match mapping {
+            Mapping::Value {
+                discriminant,
+                aliases,
+            } => {
+                named_variants.push(ident);
+                named_allows.push(allow.clone());
+                // clap would list a shared discriminant once for each variant.
+                if !is_secondary(ident) {
+                    listed_variants.push((ident, allow.clone()));
+                    possible_values.push(quote! {
+                        #allow
+                        #enum_ident::#ident => ::clap::builder::PossibleValue::new(#discriminant)
+                            #(.alias(#aliases))*,
+                    });
+                }
+                let encoded = encode_value(discriminant);
+                encode_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident => #encoded,
+                });
+                let variant = known(quote! { #enum_ident::#ident });
+                // A shared discriminant only decodes to the primary variant.
+                let decoded = (!is_secondary(ident)).then_some(discriminant);
+                for value in decoded.into_iter().chain(aliases) {
+                    let arm = |body: &TokenStream2| {
+                        if case_insensitive {
+                            quote! {
+                                #allow
+                                _ if ::core::primitive::str::eq_ignore_ascii_case(
+                                    #data_type_match,
+                                    #value,
+                                ) => #body,
+                            }
+                        } else if float_type {
+                            // Floats are compared by their bits, so that NaNs
+                            // can be matched and zeros are told apart.
+                            quote! {
+                                #allow
+                                _ if <#data_type>::to_bits(#data_type_match)
+                                    == <#data_type>::to_bits(#value) => #body,
+                            }
+                        } else if is_pattern(value) {
+                            quote! {
+                                #allow
+                                #value => #body,
+                            }
+                        } else {
+                            quote! {
+                                #allow
+                                _ if #data_type_match == #value => #body,
+                            }
+                        }
+                    };
+                    known_checks.push(arm(&quote! { true }));
+                    if let Some(since) = since {
+                        since_arms.push(arm(&quote! { #since }));
+                    }
+                    if !phf {
+                        decode_arms.push(arm(&variant));
+                        continue;
+                    }
+                    match value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(_), ..
+                        }) => phf_entries.push(quote! { #value => || #enum_ident::#ident, }),
+                        _ => errors.push(Error::new_spanned(
+                            value,
+                            "`phf` requires every value to be a string literal",
+                        )),
+                    }
+                }
+            }
+            Mapping::Range(range) => {
+                encode_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident(value) => value,
+                });
+                let variant = known(quote! { #enum_ident::#ident(#convert_discriminant(value)) });
+                range_arms.push(if is_pattern(range) {
+                    quote! {
+                        #allow
+                        value @ #range => #variant,
+                    }
+                } else {
+                    quote! {
+                        #allow
+                        value if (#range).contains(&value) => #variant,
+                    }
+                });
+                let range_check = |body: &TokenStream2| match range {
+                    _ if is_pattern(range) => quote! { #range => #body, },
+                    Expr::Range(range) => {
+                        let condition = range_condition(range);
+                        quote! {
+                            value if #condition => #body,
+                        }
+                    }
+                    _ => unreachable!("ranges are checked when parsing"),
+                };
+                known_checks.push(range_check(&quote! { true }));
+                if let Some(since) = since {
+                    since_arms.push(range_check(&quote! { #since }));
+                }
+            }
+            Mapping::Invalid(valid) => {
+                encode_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident(value) => value,
+                });
+                let variant = known(quote! { #enum_ident::#ident(value) });
+                range_arms.push(quote! {
+                    #allow
+                    value if !(#valid).contains(&value) => #variant,
+                });
+                let condition = range_condition(valid);
+                known_checks.push(quote! {
+                    value if !(#condition) => true,
+                });
+            }
+            Mapping::Prefix(prefix) => {
+                encode_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident(value) => value,
+                });
+                prefix_variants.push((ident, prefix));
+            }
+            Mapping::Pattern(pattern) => {
+                encode_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident(value) => value,
+                });
+                if case_insensitive {
+                    let flagged = format!("(?i){}", pattern.value());
+                    pattern_variants.push((ident, LitStr::new(&flagged, pattern.span())));
+                } else {
+                    pattern_variants.push((ident, pattern.clone()));
+                }
+            }
+            Mapping::Skip(Some(value)) => {
+                let encoded = encode_value(value);
+                encode_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident { .. } => #encoded,
+                });
+            }
+            Mapping::Skip(None) => {
+                unencodable.get_or_insert(ident);
+            }
+        }
+    }
+
+    // Longer prefixes are more specific, so they are checked first.
+    prefix_variants.sort_by_key(|(_, prefix)| std::cmp::Reverse(prefix.value().len()));
+    let pattern_lits = pattern_variants
+        .iter()
+        .map(|(_, pattern)| pattern)
+        .collect::<Vec<_>>();
+    let pattern_fns = (0..pattern_variants.len())
+        .map(|i| format_ident!("__other_pattern_{}", i))
+        .collect::<Vec<_>>();
+    // Only some syntax errors are caught by `pattern::check`, so the rest are
+    // reported on first use along with the variant.
+    let pattern_messages = pattern_variants
+        .iter()
+        .map(|(ident, _)| {
+            format!(
+                "invalid pattern for `{}::{}`: {{}}",
+                enum_ident.unraw(),
+                ident.unraw()
+            )
+        })
+        .collect::<Vec<_>>();
+    // With `phf`, the values are looked up in a map before matching the rest.
+    let decode_match = |rest: TokenStream2| {
+        if phf_entries.is_empty() {
+            return quote! {
+                match #data_type_match {
+                    #(#decode_arms)*
+                    #rest
+                }
+            };
+        }
+        let variant = known(quote! { variant() });
+        quote! {
+            match #enum_ident::__other_phf_map().get(#data_type_match) {
+                ::core::option::Option::Some(variant) => #variant,
+                ::core::option::Option::None => match #data_type_match {
+                    #rest
+                },
+            }
+        }
+    };
+    let capture_arms = |body: &dyn Fn(&Ident) -> TokenStream2| {
+        let prefix_arms = prefix_variants.iter().map(|(ident, prefix)| {
+            let variant = body(ident);
+            let allow = allow(ident);
+            if case_insensitive {
+                let len = prefix.value().len();
+                quote! {
+                    #allow
+                    _ if ::core::matches!(
+                        ::core::primitive::str::get(#data_type_match, ..#len),
+                        ::core::option::Option::Some(start) if start.eq_ignore_ascii_case(#prefix)
+                    ) => #variant,
+                }
+            } else {
+                quote! {
+                    #allow
+                    _ if ::core::primitive::str::starts_with(#data_type_match, #prefix) => #variant,
+                }
+            }
         });
+        let pattern_arms =
+            pattern_variants
+                .iter()
+                .zip(&pattern_fns)
+                .map(|((ident, _), pattern_fn)| {
+                    let variant = body(ident);
+                    let allow = allow(ident);
+                    quote! {
+                        #allow
+                        _ if #enum_ident::#pattern_fn().is_match(#data_type_match) => #variant,
+                    }
+                });
+        prefix_arms.chain(pattern_arms).collect::<Vec<_>>()
+    };
+    let owned_capture_arms = capture_arms(&|ident| {
+        known(quote! {
+            #enum_ident::#ident({
+                #release_value
+                #stored_value
+            })
+        })
+    });
+    known_checks.extend(capture_arms(&|_| quote! { true }));
+
+    if let (true, Some(ident)) = (into_iter, unencodable) {
+        errors.push(Error::new_spanned(
+            ident,
+            "`into_iter` requires every variant to be encodable\n\
+             help: give this skipped variant a discriminant to encode it as",
+        ));
+    }
+    if let (true, Some(ident)) = (serde_json, unencodable) {
+        errors.push(Error::new_spanned(
+            ident,
+            "`serde_json` requires every variant to be encodable\n\
+             help: give this skipped variant a discriminant to encode it as",
+        ));
+    }
+    if let (true, Some(ident)) = (bitmask, unencodable) {
+        errors.push(Error::new_spanned(
+            ident,
+            "`bitmask` requires every variant to be encodable\n\
+             help: give this skipped variant a discriminant to encode it as",
+        ));
+    }
+    errors.finish()?;
+
+    // Nothing is constructed, so primitive integers can be checked in const
+    // contexts.
+    let is_known_fn = {
+        let (name, param) = if string_type || cow_str_type {
+            (quote! { is_known_str }, quote! { &str })
+        } else {
+            (quote! { is_known_value }, quote! { #data_type })
+        };
+        let constness = is_primitive_int(&data_type).then(|| quote! { const });
+        let body = if known_checks.is_empty() {
+            quote! {
+                let _ = value;
+                false
+            }
+        } else {
+            quote! {
+                #normalize_value
+                match #data_type_match {
+                    #(#known_checks)*
+                    _ => false,
+                }
+            }
+        };
+        quote! {
+            #[doc = concat!(
+                "Returns whether `value` converts to a known variant of [`",
+                stringify!(#enum_ident), "`], without constructing it.",
+            )]
+            #[allow(clippy::match_like_matches_macro)]
+            #vis #constness fn #name(value: #param) -> bool {
+                #body
+            }
+        }
+    };
 
-    let convert_discriminant = match discriminants.first() {
-        Some(Expr::Lit(ExprLit {
-            lit: Lit::Str(_), ..
-        })) => quote! { ::std::string::ToString::to_string },
-        _ => quote! {},
+    // Strings and bytes are listed as the literals they are matched against.
+    let discriminants_const = {
+        let discriminants = mappings
+            .iter()
+            .filter_map(|(_, mapping)| match mapping {
+                Mapping::Value { discriminant, .. } if nonzero_type || inner.is_some() => {
+                    Some(encode_value(discriminant))
+                }
+                Mapping::Value { discriminant, .. } => Some(quote! { #discriminant }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let len = discriminants.len();
+        let element = if string_type || cow_str_type {
+            quote! { &'static str }
+        } else if is_bytes_type(&data_type) {
+            quote! { &'static [u8] }
+        } else {
+            quote! { #data_type }
+        };
+        quote! {
+            #[doc = concat!(
+                "The discriminants of the known variants of [`", stringify!(#enum_ident),
+                "`], in the order they are declared.",
+            )]
+            #vis const DISCRIMINANTS: [#element; #len] = [#(#discriminants),*];
+        }
     };
 
-    let stream = TokenStream::from(quote! {
-        #item
+    let from_name_ci_fn = from_name_ci.then(|| {
+        quote! {
+            /// Returns the variant with the given name, ignoring ASCII case.
+            #vis fn from_name_ignore_ascii_case(name: &str) -> ::core::option::Option<Self> {
+                #(
+                    #named_allows
+                    if name.eq_ignore_ascii_case(stringify!(#named_variants)) {
+                        return ::core::option::Option::Some(Self::#named_variants);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        }
+    });
+
+    // The map hands out references, so it holds constructors to return the
+    // variants by value.
+    let phf_map_fn = (!phf_entries.is_empty()).then(|| {
+        let allow = named_allows.iter().flatten().next();
+        quote! {
+            #[doc(hidden)]
+            #allow
+            fn __other_phf_map() -> &'static ::phf::Map<&'static str, fn() -> Self> {
+                static MAP: ::phf::Map<&'static str, fn() -> #enum_ident> = ::phf::phf_map! {
+                    #(#phf_entries)*
+                };
+                &MAP
+            }
+        }
+    });
+
+    let bitmask_impls = bitmask.then(|| {
+        quote! {
+            impl #enum_ident {
+                /// Returns whether all bits set in `flag` are also set in `self`.
+                #vis fn contains(self, flag: Self) -> bool {
+                    let flag = <#data_type>::from(flag);
+                    <#data_type>::from(self) & flag == flag
+                }
+
+                /// Returns the bits set in either `self` or `other`.
+                #vis fn union(self, other: Self) -> Self {
+                    Self::from(<#data_type>::from(self) | <#data_type>::from(other))
+                }
+
+                /// Returns the bits set in both `self` and `other`.
+                #vis fn intersection(self, other: Self) -> Self {
+                    Self::from(<#data_type>::from(self) & <#data_type>::from(other))
+                }
+            }
+
+            impl ::core::ops::BitOr for #enum_ident {
+                type Output = Self;
+
+                fn bitor(self, rhs: Self) -> Self {
+                    self.union(rhs)
+                }
+            }
+
+            impl ::core::ops::BitAnd for #enum_ident {
+                type Output = Self;
+
+                fn bitand(self, rhs: Self) -> Self {
+                    self.intersection(rhs)
+                }
+            }
+
+            impl ::core::ops::BitXor for #enum_ident {
+                type Output = Self;
+
+                fn bitxor(self, rhs: Self) -> Self {
+                    Self::from(<#data_type>::from(self) ^ <#data_type>::from(rhs))
+                }
+            }
+
+            impl ::core::ops::Not for #enum_ident {
+                type Output = Self;
+
+                fn not(self) -> Self {
+                    Self::from(!<#data_type>::from(self))
+                }
+            }
+        }
+    });
+
+    // Index expressions only unsize arrays, so vectors need their own impls.
+    let index_impls = indexable.then(|| {
+        let containers = [quote! { [T] }, quote! { ::std::vec::Vec<T> }];
+        quote! {
+            #(
+                impl<T> ::core::ops::Index<#enum_ident> for #containers {
+                    type Output = T;
+
+                    fn index(&self, index: #enum_ident) -> &T {
+                        &self[usize::from(index)]
+                    }
+                }
+
+                impl<T> ::core::ops::IndexMut<#enum_ident> for #containers {
+                    fn index_mut(&mut self, index: #enum_ident) -> &mut T {
+                        &mut self[usize::from(index)]
+                    }
+                }
+            )*
+        }
+    });
+
+    // Lists which values go to which variant on both conversions.
+    let table_doc = doc_table.then(|| {
+        let code = |tokens: &dyn ToTokens| {
+            format!(
+                "`{}`",
+                tokens.to_token_stream().to_string().replace('|', "\\|")
+            )
+        };
+        let mut lines = vec![
+            " | Value | Variant |".to_owned(),
+            " | ----- | ------- |".to_owned(),
+        ];
+        for (ident, mapping) in &mappings {
+            let values = match mapping {
+                Mapping::Value {
+                    discriminant,
+                    aliases,
+                } => iter::once(discriminant)
+                    .chain(aliases)
+                    .map(|value| code(value))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Mapping::Range(range) => code(range),
+                Mapping::Prefix(prefix) => format!("starting with {}", code(prefix)),
+                Mapping::Pattern(pattern) => format!("matching {}", code(pattern)),
+                Mapping::Invalid(valid) => format!("outside {}", code(valid)),
+                Mapping::Skip(_) => continue,
+            };
+            lines.push(format!(
+                " | {} | [`{}`]({}::{}) |",
+                values, ident, enum_ident, ident
+            ));
+        }
+        if !strict {
+            lines.push(format!(
+                " | any other value | [`{}`]({}::{}) |",
+                other_ident, enum_ident, other_ident
+            ));
+        }
+        quote! {
+            #[doc = ""]
+            #(#[doc = #lines])*
+        }
+    });
+
+    let into_iter_impl = into_iter.then(|| {
+        let element_type = tuple_element_type(&data_type);
+        let elements = (0..other_fields.len())
+            .map(|i| format_ident!("_{}", i))
+            .collect::<Vec<_>>();
+        let len = elements.len();
+        quote! {
+            impl ::core::iter::IntoIterator for #enum_ident {
+                type Item = #element_type;
+                type IntoIter = ::core::array::IntoIter<#element_type, #len>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    let (#(#elements,)*) = <#data_type>::from(self);
+                    ::core::iter::IntoIterator::into_iter([#(#elements),*])
+                }
+            }
+        }
+    });
 
-        impl ::core::convert::From<#enum_ident> for #data_type {
-            fn from(value: #enum_ident) -> Self {
-                match value {
+    // Collecting panics unless there are exactly enough elements, which
+    // `try_from_iter` checks instead.
+    let from_iter_impl = from_iter.then(|| {
+        let element_type = tuple_element_type(&data_type);
+        let elements = (0..other_fields.len())
+            .map(|i| format_ident!("_{}", i))
+            .collect::<Vec<_>>();
+        let message = format!(
+            "expected exactly {} elements to collect into `{}`",
+            elements.len(),
+            enum_ident.unraw(),
+        );
+        let doc = format!(
+            " Converts the elements of `iter`, or returns `None` if it does not yield exactly {}.",
+            elements.len(),
+        );
+        quote! {
+            impl #enum_ident {
+                #[doc = #doc]
+                #vis fn try_from_iter<I>(iter: I) -> ::core::option::Option<Self>
+                where
+                    I: ::core::iter::IntoIterator<Item = #element_type>,
+                {
+                    let mut iter = ::core::iter::IntoIterator::into_iter(iter);
                     #(
-                        #enum_ident::#primary_variants => #convert_discriminant(#discriminants),
+                        let #elements = iter.next()?;
                     )*
-                    #enum_ident :: #other_ident(
-                        #(
-                            #other_fields_pattern
-                        ),*
-                    ) => (
+                    if iter.next().is_some() {
+                        return ::core::option::Option::None;
+                    }
+                    ::core::option::Option::Some(<Self as ::core::convert::From<#data_type>>::from(
+                        (#(#elements,)*),
+                    ))
+                }
+            }
+
+            impl ::core::iter::FromIterator<#element_type> for #enum_ident {
+                fn from_iter<I>(iter: I) -> Self
+                where
+                    I: ::core::iter::IntoIterator<Item = #element_type>,
+                {
+                    match Self::try_from_iter(iter) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => ::core::panic!(#message),
+                    }
+                }
+            }
+        }
+    });
+
+    // Everything referring to the "other" variant has to be configured out
+    // along with it, and must not trip its deprecation.
+    let other_cfgs = other_attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect::<Vec<_>>();
+    let allow_deprecated = other_attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("deprecated"))
+        .then(|| quote! { #[allow(deprecated)] });
+
+    // Each field of the "other" variant defaults on its own, so tuples do not
+    // need to implement `Default` as a whole.
+    let default_impl = default_other.then(|| {
+        let values = other_fields
+            .iter()
+            .map(|_| quote! { ::core::default::Default::default() });
+        let construct = other_variant(&other_ident, other_field_names.as_ref(), values);
+        let bounds = other_fields.iter();
+        quote! {
+            #(#other_cfgs)*
+            #allow_deprecated
+            impl ::core::default::Default for #enum_ident
+            where
+                #(#bounds: ::core::default::Default,)*
+            {
+                fn default() -> Self {
+                    Self::#construct
+                }
+            }
+        }
+    });
+
+    // The hook only sees the value, so it cannot change what is decoded.
+    let on_unknown_call = |value: TokenStream2| {
+        on_unknown
+            .as_ref()
+            .map(|on_unknown| quote! { #on_unknown(#value); })
+    };
+    // Debug builds treat reaching the "other" variant as a bug, while release
+    // builds still fall back to it.
+    let deny_unknown_check = deny_unknown.then(|| {
+        let message = format!(
+            "unknown value converted into `{}`, which denies unknown values",
+            enum_ident.unraw(),
+        );
+        quote! {
+            if ::core::cfg!(debug_assertions) {
+                ::core::unreachable!(#message);
+            }
+        }
+    });
+
+    // Boxes are moved out of, strings are copied out of the stored type, and
+    // other types convert back on their own.
+    let encode_stored = |stored: TokenStream2| {
+        if boxed {
+            quote! { *#stored }
+        } else if string_type {
+            quote! {
+                ::std::string::ToString::to_string(::core::convert::AsRef::<str>::as_ref(&#stored))
+            }
+        } else {
+            quote! { <#data_type>::from(#stored) }
+        }
+    };
+
+    let (other_encode_arm, other_ref_encode_arm, decode_impl, other_fns, other_display_arms) =
+        if exhaustive {
+            // Every value is covered, which the compiler cannot see through
+            // guards.
+            let decode_match = decode_match(quote! {
+                #(#owned_capture_arms)*
+                #(#range_arms)*
+                _ => ::core::unreachable!("every value is covered by a variant"),
+            });
+            let decode_impl = quote! {
+                #table_doc
+                impl ::core::convert::From<#data_type> for #enum_ident {
+                    fn from(value: #data_type) -> Self {
+                        #decode_match
+                    }
+                }
+            };
+            (None, None, decode_impl, None, None)
+        } else if strict {
+            let decode_match = decode_match(quote! {
+                #(#owned_capture_arms)*
+                #(#range_arms)*
+                _ => {
+                    #release_value
+                    ::core::result::Result::Err(value)
+                }
+            });
+            let decode_impl = quote! {
+                #table_doc
+                impl ::core::convert::TryFrom<#data_type> for #enum_ident {
+                    type Error = #data_type;
+
+                    fn try_from(value: #data_type) -> ::core::result::Result<Self, Self::Error> {
+                        #normalize_value
+                        #decode_match
+                    }
+                }
+            };
+            (None, None, decode_impl, None, None)
+        } else {
+            if let Some(names) = &other_field_names {
+                if names.len() != other_fields.len() {
+                    return Err(Error::new_spanned(
+                        names,
+                        format!(
+                            "expected {} field names for `{}`, found {}",
+                            other_fields.len(),
+                            quote! { #data_type },
+                            names.len(),
+                        ),
+                    ));
+                }
+            }
+            let names = other_field_names.as_ref();
+            let other_decl = other_variant(&other_ident, names, &other_fields);
+            let variant = parse_quote! { #(#other_attrs)* #other_decl };
+            match other_index {
+                Some(i) => item.variants.insert(i, variant),
+                None => item.variants.push(variant),
+            }
+
+            let other_fields_pattern = (0..other_fields.len())
+                .map(|i| format_ident!("_{}", i))
+                .collect::<Vec<Ident>>();
+            let other_pattern = other_variant(&other_ident, names, &other_fields_pattern);
+
+            let known_arm = (!mappings.is_empty())
+                .then(|| quote! { known => ::core::result::Result::Ok(known), });
+            let not_other_arm =
+                (!mappings.is_empty()).then(|| quote! { _ => ::core::option::Option::None, });
+
+            let other_encode_arm = match &fallback_into {
+                Some(_) => quote! {
+                    #(#other_cfgs)*
+                    #allow_deprecated
+                    #enum_ident::#other_pattern => <#data_type>::from(_0),
+                },
+                None if stored_type.is_some() => {
+                    let encoded = encode_stored(quote! { _0 });
+                    quote! {
+                        #(#other_cfgs)*
+                        #allow_deprecated
+                        #enum_ident::#other_pattern => #encoded,
+                    }
+                }
+                None => quote! {
+                    #(#other_cfgs)*
+                    #allow_deprecated
+                    #enum_ident::#other_pattern => (
                         #(
                             #other_fields_pattern
                         ),*
                     ),
+                },
+            };
+            // Only a reference to the nested enum can be taken from a reference.
+            let other_ref_pattern = other_variant(&other_ident, names, [quote! { ref _0 }]);
+            let other_ref_encode_arm = if fallback_into.is_some() {
+                Some(quote! {
+                    #(#other_cfgs)*
+                    #allow_deprecated
+                    #enum_ident::#other_ref_pattern => <#data_type>::from(_0),
+                })
+            } else {
+                stored_type.as_ref().map(|_| {
+                    let encoded = encode_stored(quote! { ::core::clone::Clone::clone(_0) });
+                    quote! {
+                        #(#other_cfgs)*
+                        #allow_deprecated
+                        #enum_ident::#other_ref_pattern => #encoded,
+                    }
+                })
+            };
+
+            let other_values = match &data_type {
+                _ if fallback_into.is_some() || stored_type.is_some() => {
+                    vec![quote! { <#other_fields>::from(value) }]
+                }
+                _ if tuple_like => (0..other_fields.len())
+                    .map(|i| {
+                        let i = Index::from(i);
+                        quote! { value.#i }
+                    })
+                    .collect(),
+                _ => vec![quote! { value }],
+            };
+
+            let other_construct = other_variant(&other_ident, names, &other_values);
+
+            let on_unknown_value = on_unknown_call(if string_type || cow_str_type {
+                quote! { ::core::convert::AsRef::<str>::as_ref(&value) }
+            } else {
+                quote! { &value }
+            });
+            let from_match = decode_match(quote! {
+                #(#owned_capture_arms)*
+                #(#range_arms)*
+                _ => {
+                    #release_value
+                    cold_other(#stored_value)
+                }
+            });
+            let decode_impl = quote! {
+                #table_doc
+                #(#other_cfgs)*
+                #allow_deprecated
+                impl ::core::convert::From<#data_type> for #enum_ident {
+                    fn from(value: #data_type) -> Self {
+                        // Unknown values are expected to be rare, so keep them out
+                        // of the way of the known variants.
+                        #[cold]
+                        #[inline(never)]
+                        fn cold_other(value: #data_type) -> #enum_ident {
+                            #on_unknown_value
+                            #deny_unknown_check
+                            #enum_ident::#other_construct
+                        }
+
+                        #normalize_value
+                        #from_match
+                    }
+                }
+            };
+
+            let try_from_known_body =
+                if decode_arms.is_empty() && phf_entries.is_empty() && range_arms.is_empty() {
+                    quote! {
+                        let _ = value;
+                        ::core::option::Option::None
+                    }
+                } else {
+                    let decode_match = decode_match(quote! {
+                        #(#owned_capture_arms)*
+                        #(#range_arms)*
+                        _ => return ::core::option::Option::None,
+                    });
+                    quote! {
+                        #normalize_value
+                        ::core::option::Option::Some(#decode_match)
+                    }
+                };
+
+            let held_type = match fallback_into.as_ref().or(stored_type.as_ref()) {
+                Some(inner) => inner,
+                None => &data_type,
+            };
+            // Values are only looked up a second time when they are not too new.
+            let from_with_version_fn = (decode && !since_arms.is_empty()).then(|| {
+                let store_value = store_normalized.then(|| {
+                    quote! {
+                        #normalize_value
+                        let value = #stored_value;
+                    }
+                });
+                quote! {
+                    #[doc = concat!(
+                        "Converts `value` like `From`, except that values of variants introduced ",
+                        "after `version` are held by [`", stringify!(#enum_ident), "::",
+                        stringify!(#other_ident), "`].",
+                    )]
+                    #(#other_cfgs)*
+                    #allow_deprecated
+                    #vis fn from_with_version(value: #data_type, version: u32) -> Self {
+                        let since: u32 = {
+                            #normalize_value
+                            match #data_type_match {
+                                #(#since_arms)*
+                                _ => 0,
+                            }
+                        };
+                        if version < since {
+                            #store_value
+                            return #enum_ident::#other_construct;
+                        }
+                        <Self as ::core::convert::From<#data_type>>::from(value)
+                    }
+                }
+            });
+            let other_fns = quote! {
+                #from_with_version_fn
+
+                #[doc = concat!(
+                    "Returns the variant `value` converts to, or `None` if it would be held by [`",
+                    stringify!(#enum_ident), "::", stringify!(#other_ident), "`].",
+                )]
+                #vis fn try_from_known(value: #data_type) -> ::core::option::Option<Self> {
+                    #try_from_known_body
+                }
+
+                #[doc = concat!(
+                    "Returns the value held by [`", stringify!(#enum_ident), "::", stringify!(#other_ident),
+                    "`], or `None` for every other variant.",
+                )]
+                #(#other_cfgs)*
+                #allow_deprecated
+                #vis fn into_other(self) -> ::core::option::Option<#held_type> {
+                    match self {
+                        Self::#other_pattern => {
+                            ::core::option::Option::Some((#(#other_fields_pattern),*))
+                        }
+                        #not_other_arm
+                    }
+                }
+
+                #[doc = concat!(
+                    "Returns the value held by [`", stringify!(#enum_ident), "::", stringify!(#other_ident),
+                    "`] as an error, or the variant itself if it is known.",
+                )]
+                #(#other_cfgs)*
+                #allow_deprecated
+                #vis fn try_into_known(self) -> ::core::result::Result<Self, #held_type> {
+                    match self {
+                        Self::#other_pattern => {
+                            ::core::result::Result::Err((#(#other_fields_pattern),*))
+                        }
+                        #known_arm
+                    }
                 }
+            };
+
+            let other_display = match (other_fields_pattern.len(), boxed_elements) {
+                (1, Some(len)) => {
+                    let format = format!("({})", vec!["{}"; len].join(", "));
+                    let elements = (0..len).map(Index::from);
+                    quote! { ::core::write!(f, #format, #(_0.#elements),*) }
+                }
+                (1, None) => quote! { ::core::fmt::Display::fmt(_0, f) },
+                (len, _) => {
+                    let format = format!("({})", vec!["{}"; len].join(", "));
+                    quote! { ::core::write!(f, #format, #(#other_fields_pattern),*) }
+                }
+            };
+            let other_display_arms = (
+                quote! {
+                    #(#other_cfgs)*
+                    #allow_deprecated
+                    Self::#other_ident { .. } => ::core::option::Option::None,
+                },
+                quote! {
+                    #(#other_cfgs)*
+                    #allow_deprecated
+                    Self::#other_pattern => #other_display,
+                },
+            );
+
+            (
+                Some(other_encode_arm),
+                other_ref_encode_arm,
+                decode_impl,
+                Some(other_fns),
+                Some(other_display_arms),
+            )
+        };
+
+    // With a primitive representation given, the discriminants are kept so
+    // that the tags of the known variants match their values.
+    if let Some(repr_ident) = repr_int(&item.attrs).filter(|_| is_primitive_int(&data_type)) {
+        let mut errors = Errors::default();
+        let bounds = int_bounds(&parse_quote! { #repr_ident });
+        let kept = |ident: &Ident| {
+            // Tags must be distinct, so only the primary of the variants
+            // sharing a discriminant keeps it.
+            mappings.iter().find_map(|(mapped, mapping)| match mapping {
+                Mapping::Value { discriminant, .. } | Mapping::Skip(Some(discriminant))
+                    if mapped == ident && !is_secondary(ident) =>
+                {
+                    Some(discriminant)
+                }
+                _ => None,
+            })
+        };
+
+        let mut tags = HashMap::new();
+        for variant in &item.variants {
+            if let Some(Ok(Some(int))) = kept(&variant.ident).map(parse_int_expr) {
+                if matches!(bounds, Some((min, max)) if int < min || int > max) {
+                    errors.push(Error::new_spanned(
+                        kept(&variant.ident),
+                        format!(
+                            "discriminant {} does not fit in the `#[repr({})]` of the enum, so it cannot be kept\n\
+                             help: use `#[repr({})]` instead",
+                            int,
+                            repr_ident,
+                            quote! { #data_type },
+                        ),
+                    ));
+                }
+                tags.insert(int, variant.ident.clone());
             }
         }
 
-        impl ::core::convert::From<#data_type> for #enum_ident {
-            fn from(value: #data_type) -> Self {
-                match #data_type_match {
+        // The other variants are given the tags following the one before them.
+        let mut next = Some(0);
+        for variant in &mut item.variants {
+            if let Some(discriminant) = kept(&variant.ident) {
+                variant.discriminant = Some((Default::default(), discriminant.clone()));
+                // Those out of range are already reported.
+                next = parse_int_expr(discriminant)
+                    .ok()
+                    .flatten()
+                    .filter(|int| !matches!(bounds, Some((min, max)) if *int < min || *int > max))
+                    .and_then(|int| int.checked_add(1));
+                continue;
+            }
+            match next {
+                Some(tag) if matches!(bounds, Some((_, max)) if tag > max) => {
+                    errors.push(Error::new(
+                        variant.ident.span(),
+                        format!(
+                            "no tag is left for `{}` after the discriminant before it in the `#[repr({})]`\n\
+                             help: move the variant before it",
+                            variant.ident, repr_ident,
+                        ),
+                    ));
+                }
+                Some(tag) => {
+                    if let Some(taken) = tags.get(&tag) {
+                        let mut error = Error::new(
+                            variant.ident.span(),
+                            format!(
+                                "`{}` would be given the tag {}, which is the discriminant of `{}`\n\
+                                 help: move the variant after one whose next value is free",
+                                variant.ident, tag, taken,
+                            ),
+                        );
+                        error.combine(Error::new(taken.span(), "discriminant given here"));
+                        errors.push(error);
+                    }
+                }
+                None => (),
+            }
+            next = next.and_then(|tag| tag.checked_add(1));
+        }
+        errors.finish()?;
+    }
+
+    // Known variants are shown with their value, unless `display` gives them
+    // labels of their own.
+    let error_impl = error.then(|| {
+        let display_impl = (!display).then(|| {
+            let arms = mappings.iter().map(|(ident, mapping)| {
+                let allow = allow(ident);
+                let name = ident.unraw().to_string();
+                let format = format!("{} ({{}})", name);
+                match mapping {
+                    Mapping::Value { discriminant, .. } | Mapping::Skip(Some(discriminant)) => {
+                        quote! {
+                            #allow
+                            Self::#ident { .. } => ::core::write!(f, #format, #discriminant),
+                        }
+                    }
+                    Mapping::Skip(None) => quote! {
+                        #allow
+                        Self::#ident { .. } => f.write_str(#name),
+                    },
+                    Mapping::Range(_)
+                    | Mapping::Prefix(_)
+                    | Mapping::Pattern(_)
+                    | Mapping::Invalid(_) => quote! {
+                        #allow
+                        Self::#ident(value) => ::core::write!(f, #format, value),
+                    },
+                }
+            });
+            let other_arm = other_display_arms.as_ref().map(|(_, arm)| arm);
+            quote! {
+                impl ::core::fmt::Display for #enum_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            #(#arms)*
+                            #other_arm
+                        }
+                    }
+                }
+            }
+        });
+        quote! {
+            #display_impl
+
+            impl ::std::error::Error for #enum_ident {}
+        }
+    });
+
+    let (label_fn, display_impl) = if display {
+        let (label_idents, label_strs): (Vec<_>, Vec<_>) = labels.into_iter().unzip();
+        let label_allows = label_idents.iter().map(allow).collect::<Vec<_>>();
+        let (other_label_arm, other_display_arm) = other_display_arms.unzip();
+        let label_fn = quote! {
+            /// Returns the label of the variant, or `None` for values without
+            /// one.
+            #vis fn label(&self) -> ::core::option::Option<&'static str> {
+                match self {
                     #(
-                        #discriminants => Self::#primary_variants,
+                        #label_allows
+                        Self::#label_idents { .. } => ::core::option::Option::Some(#label_strs),
                     )*
-                    (
-                        #(
-                            #other_fields_pattern
-                        ),*
-                    ) => Self::#other_ident(
+                    #other_label_arm
+                }
+            }
+        };
+        let display_impl = quote! {
+            impl ::core::fmt::Display for #enum_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
                         #(
-                            #convert_discriminant(
-                                #other_fields_pattern
+                            #label_allows
+                            Self::#label_idents { .. } => f.write_str(#label_strs),
+                        )*
+                        #other_display_arm
+                    }
+                }
+            }
+        };
+        (Some(label_fn), Some(display_impl))
+    } else {
+        (None, None)
+    };
+
+    let stored_str = if store_normalized {
+        quote! { ::std::borrow::Cow::into_owned(normalized) }
+    } else {
+        quote! { ::std::borrow::ToOwned::to_owned(value) }
+    };
+    let borrowed_capture_arms =
+        capture_arms(&|ident| known(quote! { #enum_ident::#ident(#stored_str) }));
+    let other_from_str = match fallback_into.as_ref().or(stored_type.as_ref()) {
+        Some(inner) => other_variant(
+            &other_ident,
+            other_field_names.as_ref(),
+            [quote! { <#inner>::from(#stored_str) }],
+        ),
+        None => other_variant(&other_ident, other_field_names.as_ref(), [&stored_str]),
+    };
+
+    // Strings are matched by reference, only being copied if they are kept.
+    let from_str_impl = (string_type && !strict).then(|| {
+        let on_unknown_str = on_unknown_call(if store_normalized {
+            quote! { ::core::convert::AsRef::<str>::as_ref(&normalized) }
+        } else {
+            quote! { value }
+        });
+        let decode_match = decode_match(quote! {
+            #(#borrowed_capture_arms)*
+            #(#range_arms)*
+            _ => {
+                #on_unknown_str
+                #deny_unknown_check
+                #enum_ident::#other_from_str
+            }
+        });
+        quote! {
+            #(#other_cfgs)*
+            #allow_deprecated
+            impl ::core::convert::From<&str> for #enum_ident {
+                fn from(value: &str) -> Self {
+                    #normalize_value
+                    #decode_match
+                }
+            }
+        }
+    });
+
+    // Byte strings are matched by reference as well. Unknown ones are copied
+    // anyway, so they go through the conversion from the data type.
+    let from_bytes_impl = (bytes_type && !strict).then(|| {
+        let decode_match = decode_match(quote! {
+            _ => <Self as ::core::convert::From<#data_type>>::from(
+                #convert_discriminant(value),
+            ),
+        });
+        quote! {
+            #(#other_cfgs)*
+            #allow_deprecated
+            impl ::core::convert::From<&[::core::primitive::u8]> for #enum_ident {
+                fn from(value: &[::core::primitive::u8]) -> Self {
+                    #decode_match
+                }
+            }
+        }
+    });
+
+    let serde_impl = serde.then(|| {
+        let (visit_str, visit_string) = if strict {
+            let decode_match = decode_match(quote! {
+                #(#borrowed_capture_arms)*
+                #(#range_arms)*
+                _ => ::core::result::Result::Err(::serde::de::Error::invalid_value(
+                    ::serde::de::Unexpected::Str(value),
+                    &self,
+                )),
+            });
+            (
+                quote! {
+                    #normalize_value
+                    #decode_match
+                },
+                quote! {
+                    <#enum_ident as ::core::convert::TryFrom<#data_type>>::try_from(value).map_err(
+                        |value| {
+                            ::serde::de::Error::invalid_value(
+                                ::serde::de::Unexpected::Str(&value),
+                                &self,
                             )
-                        ),*
-                    ),
+                        },
+                    )
+                },
+            )
+        } else {
+            (
+                quote! {
+                    ::core::result::Result::Ok(<#enum_ident as ::core::convert::From<&str>>::from(value))
+                },
+                quote! {
+                    ::core::result::Result::Ok(
+                        <#enum_ident as ::core::convert::From<#data_type>>::from(value),
+                    )
+                },
+            )
+        };
+
+        quote! {
+            #(#other_cfgs)*
+            #allow_deprecated
+            impl<'de> ::serde::Deserialize<'de> for #enum_ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    struct Visitor;
+
+                    #allow_deprecated
+                    impl<'de> ::serde::de::Visitor<'de> for Visitor {
+                        type Value = #enum_ident;
+
+                        fn expecting(
+                            &self,
+                            f: &mut ::core::fmt::Formatter<'_>,
+                        ) -> ::core::fmt::Result {
+                            f.write_str("a string")
+                        }
+
+                        // Known strings are matched without allocating.
+                        fn visit_str<E>(self, value: &str) -> ::core::result::Result<#enum_ident, E>
+                        where
+                            E: ::serde::de::Error,
+                        {
+                            #visit_str
+                        }
+
+                        fn visit_string<E>(
+                            self,
+                            value: ::std::string::String,
+                        ) -> ::core::result::Result<#enum_ident, E>
+                        where
+                            E: ::serde::de::Error,
+                        {
+                            #visit_string
+                        }
+                    }
+
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+        }
+    });
+
+    // Values are brought to the data type before decoding, so that unknown
+    // ones end up in the "other" variant like with any other conversion.
+    let json_fns = serde_json.then(|| {
+        let value = match int_bounds(&data_type) {
+            Some((min, _)) => {
+                let as_number = if min < 0 {
+                    quote! { as_i64 }
+                } else {
+                    quote! { as_u64 }
+                };
+                quote! {
+                    <#data_type as ::core::convert::TryFrom<_>>::try_from(
+                        ::serde_json::Value::#as_number(value)?,
+                    )
+                    .ok()?
+                }
+            }
+            None => quote! {
+                <#data_type as ::core::convert::From<::std::string::String>>::from(
+                    ::std::borrow::ToOwned::to_owned(::serde_json::Value::as_str(value)?),
+                )
+            },
+        };
+        let decoded = if strict {
+            quote! { <Self as ::core::convert::TryFrom<#data_type>>::try_from(#value).ok() }
+        } else if string_type {
+            quote! {
+                ::core::option::Option::Some(<Self as ::core::convert::From<&str>>::from(
+                    ::serde_json::Value::as_str(value)?,
+                ))
+            }
+        } else {
+            quote! {
+                ::core::option::Option::Some(<Self as ::core::convert::From<#data_type>>::from(#value))
+            }
+        };
+        quote! {
+            /// Returns the value of the variant as JSON.
+            #vis fn to_json_value(&self) -> ::serde_json::Value {
+                ::serde_json::Value::from(<#data_type as ::core::convert::From<&Self>>::from(self))
+            }
+
+            /// Decodes the enum from a JSON value, or returns `None` if it
+            /// does not hold a value of the data type.
+            #vis fn from_json_value(value: &::serde_json::Value) -> ::core::option::Option<Self> {
+                #decoded
+            }
+        }
+    });
+
+    // Only variants without a value can be listed, and clap has no way of
+    // holding unknown values, so those are rejected instead.
+    let clap_impl = clap.then(|| {
+        let not_listed_arm = (possible_values.len() < item.variants.len())
+            .then(|| quote! { _ => return ::core::option::Option::None, });
+        let variants_allow = listed_variants.iter().find_map(|(_, allow)| allow.as_ref());
+        let listed_variants = listed_variants.iter().map(|(ident, _)| ident);
+        quote! {
+            impl ::clap::ValueEnum for #enum_ident {
+                fn value_variants<'a>() -> &'a [Self] {
+                    #variants_allow
+                    const VARIANTS: &[#enum_ident] = &[#(#enum_ident::#listed_variants),*];
+                    VARIANTS
+                }
+
+                fn to_possible_value(&self) -> ::core::option::Option<::clap::builder::PossibleValue> {
+                    ::core::option::Option::Some(match self {
+                        #(#possible_values)*
+                        #not_listed_arm
+                    })
+                }
+            }
+        }
+    });
+
+    let encode_impl = unencodable.is_none().then(|| {
+        quote! {
+            #table_doc
+            impl ::core::convert::From<#enum_ident> for #data_type {
+                fn from(value: #enum_ident) -> Self {
+                    match value {
+                        #(#encode_arms)*
+                        #other_encode_arm
+                    }
+                }
+            }
+        }
+    });
+
+    // The strings of known variants are borrowed for as long as the enum,
+    // just like the string held by the others.
+    let borrows_str = from_ref && string_type && unencodable.is_none() && fallback_into.is_none();
+    let str_ref_impl = borrows_str.then(|| {
+        let arms = mappings.iter().map(|(ident, mapping)| {
+            let allow = allow(ident);
+            match mapping {
+                Mapping::Value { discriminant, .. } => quote! {
+                    #allow
+                    #enum_ident::#ident => #discriminant,
+                },
+                Mapping::Skip(Some(value)) => quote! {
+                    #allow
+                    #enum_ident::#ident { .. } => #value,
+                },
+                _ => quote! {
+                    #allow
+                    #enum_ident::#ident(ref value) => ::core::convert::AsRef::<str>::as_ref(value),
+                },
+            }
+        });
+        let other_arm = (!strict).then(|| {
+            let pattern = other_variant(
+                &other_ident,
+                other_field_names.as_ref(),
+                [quote! { ref value }],
+            );
+            let value = if boxed {
+                quote! { &**value }
+            } else {
+                quote! { value }
+            };
+            quote! {
+                #(#other_cfgs)*
+                #allow_deprecated
+                #enum_ident::#pattern => ::core::convert::AsRef::<str>::as_ref(#value),
+            }
+        });
+        quote! {
+            impl<'a> ::core::convert::From<&'a #enum_ident> for &'a str {
+                fn from(value: &'a #enum_ident) -> Self {
+                    match *value {
+                        #(#arms)*
+                        #other_arm
+                    }
+                }
+            }
+        }
+    });
+
+    // Copy payloads can be taken out from behind the reference, while others
+    // need the enum to be cloned, unless they are strings that can be
+    // borrowed.
+    let ref_encode_impl =
+        (unencodable.is_none() && (from_ref || is_copy_type(&data_type))).then(|| {
+            let body = if is_copy_type(&data_type) {
+                let other_encode_arm = other_ref_encode_arm.as_ref().or(other_encode_arm.as_ref());
+                quote! {
+                    match *value {
+                        #(#encode_arms)*
+                        #other_encode_arm
+                    }
+                }
+            } else if str_ref_impl.is_some() {
+                quote! { <#data_type>::from(<&str>::from(value)) }
+            } else {
+                quote! { <#data_type>::from(::core::clone::Clone::clone(value)) }
+            };
+            quote! {
+                impl ::core::convert::From<&#enum_ident> for #data_type {
+                    fn from(value: &#enum_ident) -> Self {
+                        #body
+                    }
+                }
+            }
+        });
+
+    // Checked separately to point at the stored type if it cannot be converted.
+    let store_check = store.as_ref().map(|store| {
+        let convertible = if string_type {
+            quote! {
+                fn convertible<T: ::core::convert::From<#data_type> + ::core::convert::AsRef<str>>() {}
+            }
+        } else {
+            quote! {
+                fn convertible<T: ::core::convert::From<#data_type>>()
+                where
+                    #data_type: ::core::convert::From<T>,
+                {}
+            }
+        };
+        quote_spanned! { store.span()=>
+            const _: fn() = || {
+                #convertible
+                convertible::<#store>();
+            };
+        }
+    });
+
+    // Checked separately to point at the nested enum if it cannot be converted.
+    let fallback_into_check = fallback_into.as_ref().map(|inner| {
+        quote_spanned! { inner.span()=>
+            const _: fn() = || {
+                fn convertible<T: ::core::convert::From<U>, U: ::core::convert::From<T>>() {}
+                convertible::<#data_type, #inner>();
+            };
+        }
+    });
+
+    // Checked separately to point at the function if it has the wrong type.
+    let on_unknown_check = on_unknown.as_ref().map(|on_unknown| {
+        let param = if string_type || cow_str_type {
+            quote! { &str }
+        } else {
+            quote! { &#data_type }
+        };
+        quote_spanned! { on_unknown.span()=>
+            const _: fn(#param) = #on_unknown;
+        }
+    });
+
+    // Checked separately to point at the function if it has the wrong type.
+    let normalize_check = normalize.as_ref().map(|normalize| {
+        quote_spanned! { normalize.span()=>
+            const _: fn(&str) -> ::std::borrow::Cow<'_, str> = #normalize;
+        }
+    });
+
+    // Text written in pieces is only converted once it is complete.
+    let writer_items = writer.map(|writer| {
+        let (output, convert) = if fallible {
+            (
+                quote! { ::core::result::Result<#enum_ident, ::std::string::String> },
+                quote! { ::core::convert::TryFrom::try_from },
+            )
+        } else {
+            (
+                quote! { #enum_ident },
+                quote! { ::core::convert::From::from },
+            )
+        };
+        quote! {
+            #[doc = concat!(
+                "Collects text written with `fmt::Write`, which `finish` converts into [`",
+                stringify!(#enum_ident), "`].",
+            )]
+            #[derive(Debug, Default, Clone)]
+            #vis struct #writer {
+                text: ::std::string::String,
+            }
+
+            impl #writer {
+                /// Converts the text written so far.
+                #vis fn finish(self) -> #output {
+                    #convert(self.text)
+                }
+            }
+
+            impl ::core::fmt::Write for #writer {
+                fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                    self.text.push_str(s);
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    });
+
+    // The companion enum only has the unit variants with a discriminant, which
+    // it keeps as its own.
+    let known_items = known_enum.map(|known_enum| {
+        let mut variants = Vec::new();
+        let mut decode_arms = Vec::new();
+        let mut idents = Vec::new();
+        let mut allows = Vec::new();
+        let mut shared_arms = Vec::new();
+        for (ident, mapping) in &mappings {
+            let (discriminant, aliases) = match mapping {
+                Mapping::Value {
+                    discriminant,
+                    aliases,
+                } => (discriminant, aliases),
+                _ => continue,
+            };
+            // Tags must be distinct, so variants sharing a discriminant are
+            // known as the primary one.
+            if let Some((_, primary)) = secondary.iter().find(|(other, _)| other == ident) {
+                let allow = allow(ident);
+                shared_arms.push(quote! {
+                    #allow
+                    #enum_ident::#ident => ::core::result::Result::Ok(Self::#primary),
+                });
+                for value in aliases {
+                    decode_arms.push(if is_pattern(value) {
+                        quote! { #value => ::core::result::Result::Ok(#known_enum::#primary), }
+                    } else {
+                        quote! { _ if value == #value => ::core::result::Result::Ok(#known_enum::#primary), }
+                    });
+                }
+                continue;
+            }
+            let attrs = item
+                .variants
+                .iter()
+                .find(|variant| variant.ident == *ident)
+                .into_iter()
+                .flat_map(|variant| &variant.attrs)
+                .filter(|attr| attr.path.is_ident("doc") || attr.path.is_ident("deprecated"));
+            variants.push(quote! { #(#attrs)* #ident = #discriminant, });
+            let allow = allow(ident);
+            for value in iter::once(discriminant).chain(aliases) {
+                decode_arms.push(if is_pattern(value) {
+                    quote! { #allow #value => ::core::result::Result::Ok(#known_enum::#ident), }
+                } else {
+                    quote! { #allow _ if value == #value => ::core::result::Result::Ok(#known_enum::#ident), }
+                });
+            }
+            idents.push(ident);
+            allows.push(allow);
+        }
+        let unknown_arm = (idents.len() + shared_arms.len() < item.variants.len())
+            .then(|| quote! { _ => ::core::result::Result::Err(value), });
+
+        quote! {
+            #[doc = concat!(
+                "The variants of [`", stringify!(#enum_ident), "`] with a discriminant, which is kept.",
+            )]
+            #(#known_attrs)*
+            #[repr(#data_type)]
+            #vis enum #known_enum {
+                #(#variants)*
+            }
+
+            impl ::core::convert::From<#known_enum> for #enum_ident {
+                fn from(value: #known_enum) -> Self {
+                    match value {
+                        #(
+                            #allows
+                            #known_enum::#idents => Self::#idents,
+                        )*
+                    }
+                }
+            }
+
+            impl ::core::convert::TryFrom<#enum_ident> for #known_enum {
+                type Error = #enum_ident;
+
+                fn try_from(value: #enum_ident) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #(
+                            #allows
+                            #enum_ident::#idents => ::core::result::Result::Ok(Self::#idents),
+                        )*
+                        #(#shared_arms)*
+                        #unknown_arm
+                    }
+                }
+            }
+
+            impl ::core::convert::TryFrom<#data_type> for #known_enum {
+                type Error = #data_type;
+
+                fn try_from(value: #data_type) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #(#decode_arms)*
+                        _ => ::core::result::Result::Err(value),
+                    }
+                }
+            }
+        }
+    });
+
+    // Both enums have a fallback variant, so every value passes through.
+    let convert_with_impls = convert_with.map(|other| {
+        quote! {
+            impl ::core::convert::From<#other> for #enum_ident {
+                fn from(value: #other) -> Self {
+                    Self::from(<#data_type>::from(value))
+                }
+            }
+
+            impl ::core::convert::From<#enum_ident> for #other {
+                fn from(value: #enum_ident) -> Self {
+                    Self::from(<#data_type>::from(value))
+                }
+            }
+        }
+    });
+
+    let extra_impls = extra_mappings.iter().map(|mapping| {
+        let complete = strict && mapping.values.len() == mappings.len();
+        mapping.expand(&enum_ident, complete, &deprecated)
+    });
+
+    // `None` goes to the sentinel variant, and everything else through the
+    // conversions of the data type.
+    let (option_encode_impl, option_decode_impl) = match &sentinel {
+        Some(sentinel) => {
+            let allow = allow(sentinel);
+            let encode_impl = encode_impl.is_some().then(|| {
+                quote! {
+                    impl ::core::convert::From<#enum_ident> for ::core::option::Option<#data_type> {
+                        fn from(value: #enum_ident) -> Self {
+                            match value {
+                                #allow
+                                #enum_ident::#sentinel => ::core::option::Option::None,
+                                value => ::core::option::Option::Some(<#data_type>::from(value)),
+                            }
+                        }
+                    }
+                }
+            });
+            let decode_impl = if fallible {
+                quote! {
+                    impl ::core::convert::TryFrom<::core::option::Option<#data_type>> for #enum_ident {
+                        type Error = #data_type;
+
+                        fn try_from(
+                            value: ::core::option::Option<#data_type>,
+                        ) -> ::core::result::Result<Self, Self::Error> {
+                            match value {
+                                ::core::option::Option::Some(value) => Self::try_from(value),
+                                #allow
+                                ::core::option::Option::None => {
+                                    ::core::result::Result::Ok(Self::#sentinel)
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    impl ::core::convert::From<::core::option::Option<#data_type>> for #enum_ident {
+                        fn from(value: ::core::option::Option<#data_type>) -> Self {
+                            match value {
+                                ::core::option::Option::Some(value) => Self::from(value),
+                                #allow
+                                ::core::option::Option::None => Self::#sentinel,
+                            }
+                        }
+                    }
+                }
+            };
+            (encode_impl, Some(decode_impl))
+        }
+        None => (None, None),
+    };
+
+    // Boxed strings go through `String`, which takes over their allocation.
+    let (box_str_encode_impl, box_str_decode_impl) = if string_type {
+        let decode_impl = if fallible {
+            quote! {
+                impl ::core::convert::TryFrom<::std::boxed::Box<str>> for #enum_ident {
+                    type Error = ::std::string::String;
+
+                    fn try_from(
+                        value: ::std::boxed::Box<str>,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        Self::try_from(::std::string::String::from(value))
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl ::core::convert::From<::std::boxed::Box<str>> for #enum_ident {
+                    fn from(value: ::std::boxed::Box<str>) -> Self {
+                        Self::from(::std::string::String::from(value))
+                    }
+                }
+            }
+        };
+        let encode_impl = unencodable.is_none().then(|| {
+            quote! {
+                impl ::core::convert::From<#enum_ident> for ::std::boxed::Box<str> {
+                    fn from(value: #enum_ident) -> Self {
+                        ::std::string::String::from(value).into_boxed_str()
+                    }
+                }
+            }
+        });
+        (encode_impl, Some(decode_impl))
+    } else {
+        (None, None)
+    };
+
+    // Each enum converts to its element, and the combined value then decodes
+    // as usual.
+    let from_tuple_impl = from_tuple.map(|tuple| {
+        let elements = (0..tuple.elems.len())
+            .map(|i| format_ident!("_{}", i))
+            .collect::<Vec<_>>();
+        let combined = quote! { (#(::core::convert::From::from(#elements),)*) };
+        if fallible {
+            quote! {
+                impl ::core::convert::TryFrom<#tuple> for #enum_ident {
+                    type Error = #data_type;
+
+                    fn try_from(
+                        (#(#elements,)*): #tuple,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        <Self as ::core::convert::TryFrom<#data_type>>::try_from(#combined)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl ::core::convert::From<#tuple> for #enum_ident {
+                    fn from((#(#elements,)*): #tuple) -> Self {
+                        <Self as ::core::convert::From<#data_type>>::from(#combined)
+                    }
+                }
+            }
+        }
+    });
+
+    // Shares the visibility of the enum, as its fields name the elements
+    // through it.
+    let elements_impl = arity
+        .filter(|_| !strict && !declared_elements)
+        .map(|arity| {
+            let elements = (0..arity)
+                .map(|i| format_ident!("T{}", i))
+                .collect::<Vec<_>>();
+            let message = format!(
+                "`arity = {}` requires `{{Self}}` to be a tuple of {} elements",
+                arity, arity
+            );
+            let item_vis = &item.vis;
+            quote! {
+                #[doc(hidden)]
+                #[diagnostic::on_unimplemented(message = #message)]
+                #item_vis trait #elements_trait {
+                    #(type #elements;)*
+                }
+
+                impl<#(#elements),*> #elements_trait for (#(#elements,)*) {
+                    #(type #elements = #elements;)*
+                }
+            }
+        });
+
+    let ref_encoded = encode && ref_encode_impl.is_some();
+    // Spanned to the enum so that a trait with other signatures is reported
+    // there.
+    let trait_impl = impl_trait.map(|impl_trait| {
+        let span = enum_ident.span();
+        let path = &impl_trait.path;
+        let encode_fn = impl_trait.encode.map(|method| {
+            let value = if ref_encoded {
+                quote_spanned! { span=> self }
+            } else {
+                quote_spanned! { span=> ::core::clone::Clone::clone(self) }
+            };
+            quote_spanned! { span=>
+                fn #method(&self) -> #data_type {
+                    <#data_type as ::core::convert::From<_>>::from(#value)
+                }
+            }
+        });
+        let decode_fn = impl_trait.decode.map(|method| {
+            quote_spanned! { span=>
+                fn #method(value: #data_type) -> Self {
+                    <Self as ::core::convert::From<#data_type>>::from(value)
                 }
             }
+        });
+        quote_spanned! { span=>
+            impl #path for #enum_ident {
+                #encode_fn
+                #decode_fn
+            }
         }
     });
 
-    stream
+    // Conversions left out are implemented by hand.
+    let (encode_impl, ref_encode_impl, option_encode_impl, box_str_encode_impl) = if encode {
+        (
+            encode_impl,
+            ref_encode_impl,
+            option_encode_impl,
+            box_str_encode_impl,
+        )
+    } else {
+        (None, None, None, None)
+    };
+    let (
+        decode_impl,
+        from_str_impl,
+        from_bytes_impl,
+        option_decode_impl,
+        from_tuple_impl,
+        box_str_decode_impl,
+    ) = if decode {
+        (
+            Some(decode_impl),
+            from_str_impl,
+            from_bytes_impl,
+            option_decode_impl,
+            from_tuple_impl,
+            box_str_decode_impl,
+        )
+    } else {
+        (None, None, None, None, None, None)
+    };
+
+    let impls = quote! {
+        #normalize_check
+
+        #on_unknown_check
+
+        #fallback_into_check
+
+        #store_check
+        #(#declared_checks)*
+        #default_impl
+        #(#mask_checks)*
+
+        #encode_impl
+
+        #ref_encode_impl
+        #str_ref_impl
+
+        #decode_impl
+
+        #from_str_impl
+
+        #from_bytes_impl
+
+        #box_str_encode_impl
+
+        #box_str_decode_impl
+
+        #option_encode_impl
+
+        #option_decode_impl
+
+        #from_tuple_impl
+
+        impl #enum_ident {
+            #other_fns
+
+            /// Returns the variant with the given name.
+            ///
+            /// Variants holding a value are never returned.
+            #vis fn from_name(name: &str) -> ::core::option::Option<Self> {
+                match name {
+                    #(
+                        #named_allows
+                        stringify!(#named_variants) => {
+                            ::core::option::Option::Some(Self::#named_variants)
+                        }
+                    )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #from_name_ci_fn
+
+            #is_known_fn
+
+            #discriminants_const
+
+            #(
+                #[doc(hidden)]
+                fn #pattern_fns() -> &'static ::regex::Regex {
+                    static PATTERN: ::std::sync::OnceLock<::regex::Regex> =
+                        ::std::sync::OnceLock::new();
+                    PATTERN.get_or_init(|| {
+                        ::regex::Regex::new(#pattern_lits)
+                            .unwrap_or_else(|error| ::core::panic!(#pattern_messages, error))
+                    })
+                }
+            )*
+
+            #phf_map_fn
+
+            #label_fn
+
+            #json_fns
+        }
+
+        #bitmask_impls
+
+        #index_impls
+
+        #into_iter_impl
+        #from_iter_impl
+
+        #display_impl
+
+        #error_impl
+
+        #serde_impl
+
+        #clap_impl
+
+        #trait_impl
+
+        #elements_impl
+
+        #(#extra_impls)*
+
+        #convert_with_impls
+
+        #known_items
+
+        #writer_items
+    };
+
+    let mut impls: File = syn::parse2(impls)?;
+    for impl_item in &mut impls.items {
+        if let Some(attrs) = item_attrs(impl_item) {
+            attrs.splice(0..0, impl_attrs.iter().cloned());
+        }
+    }
+
+    Ok(impls)
 }
@@ -0,0 +1,162 @@
+//! Further mappings given with `#[other_mapping(key: Type)]`. Only the data
+//! type given to the macro has the "other" variant, so these convert through
+//! `TryFrom` in both directions.
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Attribute, Error, Expr, ExprLit, Ident, Lit, Result, Token, Type,
+};
+
+use crate::{discriminant_key, is_pattern, is_string_type, variant::OPTIONS, Errors};
+
+pub(crate) struct ExtraMapping {
+    /// Variant option giving the value in this mapping.
+    pub key: Ident,
+    pub data_type: Type,
+    /// Variants with a value in this mapping.
+    pub values: Vec<(Ident, Expr)>,
+}
+
+impl Parse for ExtraMapping {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.call(Ident::parse_any)?;
+        if OPTIONS.contains(&key.to_string().as_str()) {
+            return Err(Error::new(
+                key.span(),
+                format!("`{}` is already a variant option", key),
+            ));
+        }
+        input.parse::<Token![:]>()?;
+        let data_type = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self {
+            key,
+            data_type,
+            values: Vec::new(),
+        })
+    }
+}
+
+impl ExtraMapping {
+    /// Takes every `#[other_mapping(...)]` attribute out of `attrs` and parses
+    /// it.
+    pub fn extract(attrs: &mut Vec<Attribute>) -> Result<Vec<Self>> {
+        let mut mappings = Vec::new();
+        let mut errors = Errors::default();
+
+        attrs.retain(|attr| {
+            if !attr.path.is_ident("other_mapping") {
+                return true;
+            }
+
+            match attr.parse_args::<Self>() {
+                Ok(mapping) if mappings.iter().any(|other: &Self| other.key == mapping.key) => {
+                    errors.push(Error::new(
+                        mapping.key.span(),
+                        format!("duplicate mapping `{}`", mapping.key),
+                    ));
+                }
+                Ok(mapping) => mappings.push(mapping),
+                Err(e) => errors.push(e),
+            }
+            false
+        });
+
+        errors.finish().map(|()| mappings)
+    }
+
+    /// Reports values used by more than one variant.
+    pub fn check(&self, errors: &mut Errors) {
+        let mut seen = HashMap::<String, &Expr>::new();
+        for (_, value) in &self.values {
+            match seen.entry(discriminant_key(value, false)) {
+                Entry::Occupied(first) => {
+                    let mut error = Error::new_spanned(
+                        value,
+                        format!("duplicate value in mapping `{}`", self.key),
+                    );
+                    error.combine(Error::new_spanned(first.get(), "first used here"));
+                    errors.push(error);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+    }
+
+    /// Generates both conversions, given whether every variant of the enum
+    /// has a value in this mapping and which variants are deprecated.
+    pub fn expand(&self, enum_ident: &Ident, complete: bool, deprecated: &[Ident]) -> TokenStream2 {
+        let data_type = &self.data_type;
+        let is_str = self.values.iter().any(|(_, value)| {
+            matches!(
+                value,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(_),
+                    ..
+                })
+            )
+        });
+        let data_type_match = if is_string_type(data_type) || is_str {
+            quote! { ::core::convert::AsRef::<str>::as_ref(&value) }
+        } else {
+            quote! { value }
+        };
+        let convert =
+            is_string_type(data_type).then(|| quote! { ::std::string::ToString::to_string });
+
+        let allow = |ident: &Ident| {
+            deprecated
+                .contains(ident)
+                .then(|| quote! { #[allow(deprecated)] })
+        };
+        let decode_arms = self.values.iter().map(|(ident, value)| {
+            let allow = allow(ident);
+            let variant = quote! { ::core::result::Result::Ok(#enum_ident::#ident) };
+            if is_pattern(value) {
+                quote! { #allow #value => #variant, }
+            } else {
+                quote! { #allow _ if #data_type_match == #value => #variant, }
+            }
+        });
+        let encode_arms = self.values.iter().map(|(ident, value)| {
+            let allow = allow(ident);
+            quote! {
+                #allow
+                #enum_ident::#ident => ::core::result::Result::Ok(#convert(#value)),
+            }
+        });
+        let unmapped_arm = (!complete).then(|| quote! { _ => ::core::result::Result::Err(value), });
+
+        quote! {
+            impl ::core::convert::TryFrom<#data_type> for #enum_ident {
+                type Error = #data_type;
+
+                fn try_from(value: #data_type) -> ::core::result::Result<Self, Self::Error> {
+                    match #data_type_match {
+                        #(#decode_arms)*
+                        _ => ::core::result::Result::Err(value),
+                    }
+                }
+            }
+
+            impl ::core::convert::TryFrom<#enum_ident> for #data_type {
+                type Error = #enum_ident;
+
+                fn try_from(value: #enum_ident) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #(#encode_arms)*
+                        #unmapped_arm
+                    }
+                }
+            }
+        }
+    }
+}
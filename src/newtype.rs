@@ -0,0 +1,90 @@
+//! The `#[other_newtype(...)]` attribute, forwarding the conversions of an
+//! enum to a struct wrapping it.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Fields, ItemStruct, Member, Result, Token, Type,
+};
+
+pub(crate) struct NewtypeArgs {
+    /// Data types the enum converts to and from, in both directions.
+    data_types: Punctuated<Type, Token![,]>,
+}
+
+impl Parse for NewtypeArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            data_types: input.parse_terminated(Type::parse)?,
+        })
+    }
+}
+
+impl NewtypeArgs {
+    pub fn expand(&self, item: &ItemStruct) -> Result<TokenStream2> {
+        // The single field holds the enum, leaving nothing to be generic over.
+        if item.generics.lt_token.is_some() {
+            return Err(Error::new_spanned(
+                &item.generics,
+                "structs wrapping an enum cannot be generic",
+            ));
+        }
+        let field = match &item.fields {
+            Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => {
+                return Err(Error::new_spanned(
+                    &item.fields,
+                    "expected a single field holding the enum",
+                ))
+            }
+        };
+        let (member, construct) = match &field.ident {
+            Some(ident) => (
+                Member::Named(ident.clone()),
+                quote! { Self { #ident: value } },
+            ),
+            None => (Member::Unnamed(0.into()), quote! { Self(value) }),
+        };
+        let enum_type = &field.ty;
+        let ident = &item.ident;
+
+        // Spanned to each type so that a missing conversion of the enum points
+        // at it.
+        let data_type_impls = self.data_types.iter().map(|data_type| {
+            quote_spanned! { data_type.span()=>
+                impl ::core::convert::From<#data_type> for #ident {
+                    fn from(value: #data_type) -> Self {
+                        let value = <#enum_type as ::core::convert::From<#data_type>>::from(value);
+                        #construct
+                    }
+                }
+
+                impl ::core::convert::From<#ident> for #data_type {
+                    fn from(value: #ident) -> Self {
+                        <#data_type as ::core::convert::From<#enum_type>>::from(value.#member)
+                    }
+                }
+            }
+        });
+
+        Ok(quote! {
+            #(#data_type_impls)*
+
+            impl ::core::convert::From<#enum_type> for #ident {
+                fn from(value: #enum_type) -> Self {
+                    #construct
+                }
+            }
+
+            impl ::core::convert::From<#ident> for #enum_type {
+                fn from(value: #ident) -> Self {
+                    value.#member
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,84 @@
+//! Checks for regular expressions given to `#[other(pattern = "...")]`.
+//!
+//! The patterns are compiled by the `regex` crate at runtime, so this only
+//! catches the mistakes that can be found without parsing them fully.
+
+/// Returns a description of the first syntax error found in `pattern`.
+pub(crate) fn check(pattern: &str) -> Result<(), String> {
+    let mut groups = 0usize;
+    // Whether the previous token can be repeated.
+    let mut repeatable = false;
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if chars.next().is_none() {
+                    return Err("pattern ends with an incomplete escape".to_owned());
+                }
+                repeatable = true;
+            }
+            '(' => {
+                // Skips the `?` of flags and non-capturing groups like `(?i)`.
+                if chars.clone().next() == Some('?') {
+                    chars.next();
+                }
+                groups += 1;
+                repeatable = false;
+            }
+            ')' => {
+                if groups == 0 {
+                    return Err("unopened group".to_owned());
+                }
+                groups -= 1;
+                repeatable = true;
+            }
+            '|' => repeatable = false,
+            '*' | '+' | '?' | '{' => {
+                if !repeatable {
+                    return Err(format!("repetition operator `{}` has nothing to repeat", c));
+                }
+                if c == '{' && !chars.by_ref().any(|c| c == '}') {
+                    return Err("unclosed counted repetition".to_owned());
+                }
+                // Allows for lazy repetitions such as `*?`.
+                repeatable = true;
+            }
+            '[' => {
+                check_class(&mut chars)?;
+                repeatable = true;
+            }
+            _ => repeatable = true,
+        }
+    }
+
+    match groups {
+        0 => Ok(()),
+        _ => Err("unclosed group".to_owned()),
+    }
+}
+
+/// Skips over a character class, after its opening bracket.
+fn check_class(chars: &mut std::str::Chars) -> Result<(), String> {
+    let mut depth = 1usize;
+    let mut first = true;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '^' if first => continue,
+            ']' if first => (),
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(());
+                }
+            }
+            _ => (),
+        }
+        first = false;
+    }
+    Err("unclosed character class".to_owned())
+}
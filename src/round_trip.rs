@@ -0,0 +1,183 @@
+//! The `assert_round_trip!` and `assert_coverage!` macros, checking that
+//! values survive being converted into an enum and back.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, Expr, Result, Token, Type, TypePath,
+};
+
+pub(crate) struct RoundTrip {
+    enum_type: Type,
+    /// The type converted back into, or inferred from the values if not given.
+    data_type: Option<Type>,
+    values: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for RoundTrip {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let enum_type = input.parse()?;
+        let data_type = match input.parse::<Option<Token![:]>>()? {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
+        input.parse::<Token![,]>()?;
+        let content;
+        bracketed!(content in input);
+        let values = content.parse_terminated(Expr::parse)?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self {
+            enum_type,
+            data_type,
+            values,
+        })
+    }
+}
+
+impl RoundTrip {
+    pub fn expand(&self) -> TokenStream2 {
+        let enum_type = &self.enum_type;
+        let (data_type, convert) = match &self.data_type {
+            Some(data_type) => (
+                quote! { #data_type },
+                quote! { ::core::convert::From::from },
+            ),
+            None => (quote! { _ }, quote! {}),
+        };
+        let values = self.values.iter();
+
+        // Converting in a generic function lets the data type be inferred from
+        // both conversions, rather than just one.
+        quote! {
+            {
+                fn round_trip<E, T>(value: T) -> T
+                where
+                    E: ::core::convert::From<T>,
+                    T: ::core::convert::From<E>,
+                {
+                    T::from(E::from(value))
+                }
+
+                // Each value is evaluated once, and converted from a clone.
+                #({
+                    let expected = #values;
+                    ::core::assert_eq!(
+                        round_trip::<#enum_type, #data_type>(
+                            #convert(::core::clone::Clone::clone(&expected))
+                        ),
+                        expected,
+                        "`{}` does not round-trip through `{}`",
+                        ::core::stringify!(#values),
+                        ::core::stringify!(#enum_type),
+                    );
+                })*
+            }
+        }
+    }
+}
+
+/// Integer types whose every value is checked by `assert_coverage!`, rather
+/// than a sample of them.
+const FULL: &[&str] = &["u8", "i8", "u16", "i16"];
+
+/// Integer types only a sample of which is checked by `assert_coverage!`.
+const SAMPLED: &[&str] = &["u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize"];
+
+pub(crate) struct Coverage {
+    enum_type: Type,
+    data_type: Type,
+    full: bool,
+    signed: bool,
+}
+
+impl Parse for Coverage {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let enum_type = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let data_type = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        let name = match &data_type {
+            Type::Path(TypePath { qself: None, path }) => path.get_ident().map(ToString::to_string),
+            _ => None,
+        };
+        let (full, signed) = match name.as_deref() {
+            Some(name) if FULL.contains(&name) || SAMPLED.contains(&name) => {
+                (FULL.contains(&name), name.starts_with('i'))
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    &data_type,
+                    "`assert_coverage!` checks the values of a primitive integer type",
+                ))
+            }
+        };
+
+        Ok(Self {
+            enum_type,
+            data_type,
+            full,
+            signed,
+        })
+    }
+}
+
+impl Coverage {
+    pub fn expand(&self) -> TokenStream2 {
+        let enum_type = &self.enum_type;
+        let data_type = &self.data_type;
+
+        let checks = if self.full {
+            quote! {
+                for value in <#data_type>::MIN..=<#data_type>::MAX {
+                    check(value);
+                }
+            }
+        } else {
+            // Discriminants are mostly small or near the bounds, and flags are
+            // powers of two, so those are checked.
+            let low = if self.signed {
+                quote! { -0x8000 }
+            } else {
+                quote! { 0 }
+            };
+            quote! {
+                for value in #low..=0xffff {
+                    check(value);
+                }
+                for value in <#data_type>::MIN..=<#data_type>::MIN + 0xff {
+                    check(value);
+                }
+                for value in <#data_type>::MAX - 0xff..=<#data_type>::MAX {
+                    check(value);
+                }
+                for shift in 0..<#data_type>::BITS {
+                    let bit: #data_type = 1 << shift;
+                    check(bit);
+                    check(bit.wrapping_sub(1));
+                }
+            }
+        };
+
+        quote! {
+            {
+                let check = |value: #data_type| {
+                    ::core::assert_eq!(
+                        <#data_type as ::core::convert::From<#enum_type>>::from(
+                            <#enum_type as ::core::convert::From<#data_type>>::from(value),
+                        ),
+                        value,
+                        "`{}` does not round-trip through `{}`",
+                        value,
+                        ::core::stringify!(#enum_type),
+                    );
+                };
+                #checks
+            }
+        }
+    }
+}
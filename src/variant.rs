@@ -0,0 +1,140 @@
+//! Options given to individual variants through `#[other(...)]`.
+
+use syn::{
+    ext::IdentExt, parse::ParseStream, Attribute, Error, Expr, Ident, LitStr, Result, Token,
+};
+
+/// Options every variant accepts.
+pub(crate) const OPTIONS: &[&str] = &[
+    "alias", "display", "pattern", "prefix", "primary", "rename", "sentinel", "since", "skip",
+];
+
+#[derive(Default)]
+pub(crate) struct VariantArgs {
+    /// Extra values that decode to this variant but are never encoded.
+    pub aliases: Vec<Expr>,
+    /// Excludes the variant from the conversions.
+    pub skip: bool,
+    /// Makes the variant the one decoded from a discriminant it shares with
+    /// other variants.
+    pub primary: bool,
+    /// Makes `None` convert to this variant when converting from an `Option`.
+    pub sentinel: bool,
+    /// String used in place of the discriminant in both directions.
+    pub rename: Option<LitStr>,
+    /// Protocol version the variant was introduced in, before which its values
+    /// decode to the "other" variant in `from_with_version`.
+    pub since: Option<Expr>,
+    /// Human-facing label returned by `label` and used by `Display`.
+    pub display: Option<LitStr>,
+    /// Makes the variant hold any string starting with this prefix.
+    pub prefix: Option<LitStr>,
+    /// Makes the variant hold any string matching this regular expression.
+    pub pattern: Option<LitStr>,
+    /// Values of the variant in the mappings given with `other_mapping`.
+    pub mapped: Vec<(Ident, Expr)>,
+}
+
+impl VariantArgs {
+    /// Takes every `#[other(...)]` attribute out of `attrs` and parses it,
+    /// accepting `mapping_keys` as options for the further mappings.
+    pub fn extract(attrs: &mut Vec<Attribute>, mapping_keys: &[Ident]) -> Result<Self> {
+        let mut args = Self::default();
+        let mut result = Ok(());
+
+        attrs.retain(|attr| {
+            if !attr.path.is_ident("other") {
+                return true;
+            }
+
+            if let Err(e) =
+                attr.parse_args_with(|input: ParseStream| args.parse_options(input, mapping_keys))
+            {
+                match &mut result {
+                    Ok(()) => result = Err(e),
+                    Err(errors) => errors.combine(e),
+                }
+            }
+            false
+        });
+
+        result.map(|()| args)
+    }
+
+    fn parse_options(&mut self, input: ParseStream, mapping_keys: &[Ident]) -> Result<()> {
+        while !input.is_empty() {
+            let key = input.call(Ident::parse_any)?;
+            match key.to_string().as_str() {
+                "alias" => {
+                    input.parse::<Token![=]>()?;
+                    self.aliases.push(input.parse()?);
+                }
+                "skip" => self.skip = true,
+                "since" => {
+                    input.parse::<Token![=]>()?;
+                    if self.since.is_some() {
+                        return Err(Error::new(
+                            key.span(),
+                            format!("duplicate option `{}`", key),
+                        ));
+                    }
+                    self.since = Some(input.parse()?);
+                }
+                "sentinel" => self.sentinel = true,
+                "primary" => self.primary = true,
+                "rename" | "display" | "prefix" | "pattern" => {
+                    input.parse::<Token![=]>()?;
+                    let lit = input.parse()?;
+                    let option = match key.to_string().as_str() {
+                        "rename" => &mut self.rename,
+                        "display" => &mut self.display,
+                        "prefix" => &mut self.prefix,
+                        _ => &mut self.pattern,
+                    };
+                    if option.is_some() {
+                        return Err(Error::new(
+                            key.span(),
+                            format!("duplicate option `{}`", key),
+                        ));
+                    }
+                    *option = Some(lit);
+                }
+                _ if mapping_keys.contains(&key) => {
+                    input.parse::<Token![=]>()?;
+                    if self.mapped.iter().any(|(mapped, _)| *mapped == key) {
+                        return Err(Error::new(
+                            key.span(),
+                            format!("duplicate option `{}`", key),
+                        ));
+                    }
+                    self.mapped.push((key, input.parse()?));
+                }
+                _ => {
+                    let mut expected = mapping_keys
+                        .iter()
+                        .map(|key| key.to_string())
+                        .chain(OPTIONS.iter().map(|&option| option.to_owned()))
+                        .collect::<Vec<_>>();
+                    expected.sort();
+                    let expected = expected
+                        .iter()
+                        .map(|option| format!("`{}`", option))
+                        .collect::<Vec<_>>();
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "unknown variant option `{}`, expected one of {}",
+                            key,
+                            expected.join(", ")
+                        ),
+                    ));
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+//! The `wrapper = Name` mode, which keeps the enum as written and generates
+//! a separate enum holding either one of its variants or any other value.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Error, Fields, Ident, ItemEnum, Result, Type, Visibility};
+
+use crate::{is_pattern, Errors, VariantArgs};
+
+pub(crate) fn expand(
+    data_type: &Type,
+    other_ident: &Ident,
+    wrapper: &Ident,
+    wrapper_attrs: &[Attribute],
+    vis: &Visibility,
+    item: &mut ItemEnum,
+) -> Result<TokenStream2> {
+    let mut errors = Errors::default();
+
+    let enum_ident = &item.ident;
+    let mut decode_arms = Vec::new();
+    for variant in &mut item.variants {
+        let args = match VariantArgs::extract(&mut variant.attrs, &[]) {
+            Ok(args) => args,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        if !matches!(variant.fields, Fields::Unit) {
+            errors.push(Error::new_spanned(
+                &variant.fields,
+                "variants of an enum kept as is cannot hold data, as they are cast to the data type",
+            ));
+            continue;
+        }
+        if let Some(lit) = [&args.rename, &args.display, &args.prefix, &args.pattern]
+            .into_iter()
+            .flatten()
+            .next()
+        {
+            errors.push(Error::new_spanned(
+                lit,
+                "only `alias` and `skip` can be given to the variants of an enum kept as is",
+            ));
+        }
+        if let Some(since) = &args.since {
+            errors.push(Error::new_spanned(
+                since,
+                "only `alias` and `skip` can be given to the variants of an enum kept as is",
+            ));
+        }
+        if args.sentinel || args.primary {
+            errors.push(Error::new(
+                variant.ident.span(),
+                "only `alias` and `skip` can be given to the variants of an enum kept as is",
+            ));
+        }
+        if args.skip {
+            continue;
+        }
+
+        let ident = &variant.ident;
+        let allow = variant
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("deprecated"))
+            .then(|| quote! { #[allow(deprecated)] });
+        let known = quote! { Self::Known(#enum_ident::#ident) };
+        decode_arms.push(quote! {
+            #allow
+            _ if value == #enum_ident::#ident as #data_type => #known,
+        });
+        for alias in &args.aliases {
+            decode_arms.push(if is_pattern(alias) {
+                quote! { #allow #alias => #known, }
+            } else {
+                quote! { #allow _ if value == #alias => #known, }
+            });
+        }
+    }
+    errors.finish()?;
+
+    Ok(quote! {
+        #[doc = concat!(
+            "Either a known [`", stringify!(#enum_ident), "`] or any other `",
+            stringify!(#data_type), "`.",
+        )]
+        #(#wrapper_attrs)*
+        #vis enum #wrapper {
+            /// A value with a variant in the enum.
+            Known(#enum_ident),
+            /// Any other value.
+            #other_ident(#data_type),
+        }
+
+        impl ::core::convert::From<#data_type> for #wrapper {
+            fn from(value: #data_type) -> Self {
+                match value {
+                    #(#decode_arms)*
+                    _ => Self::#other_ident(value),
+                }
+            }
+        }
+
+        impl ::core::convert::From<#wrapper> for #data_type {
+            fn from(value: #wrapper) -> Self {
+                match value {
+                    #wrapper::Known(known) => known as #data_type,
+                    #wrapper::#other_ident(value) => value,
+                }
+            }
+        }
+
+        impl ::core::convert::From<#enum_ident> for #wrapper {
+            fn from(value: #enum_ident) -> Self {
+                Self::Known(value)
+            }
+        }
+
+        impl ::core::convert::TryFrom<#wrapper> for #enum_ident {
+            type Error = #wrapper;
+
+            fn try_from(value: #wrapper) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #wrapper::Known(known) => ::core::result::Result::Ok(known),
+                    value => ::core::result::Result::Err(value),
+                }
+            }
+        }
+    })
+}
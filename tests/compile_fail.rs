@@ -0,0 +1,120 @@
+//! Checks the errors reported for the inputs in `tests/compile_fail`.
+//!
+//! Each `<case>.rs` is built as a binary of a scratch crate depending on
+//! this one, and the errors reported for it are compared with
+//! `<case>.stderr`. Running with `COMPILE_FAIL=overwrite` writes the errors
+//! found instead.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[test]
+fn compile_fail() {
+    let cases_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+    let crate_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile_fail");
+    let bin_dir = crate_dir.join("src/bin");
+    let _ = fs::remove_dir_all(&bin_dir);
+    fs::create_dir_all(&bin_dir).unwrap();
+
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        format!(
+            "[package]\n\
+             name = \"compile-fail\"\n\
+             version = \"0.0.0\"\n\
+             edition = \"2021\"\n\
+             \n\
+             [workspace]\n\
+             \n\
+             [dependencies]\n\
+             enum-other = {{ path = {:?} }}\n",
+            env!("CARGO_MANIFEST_DIR"),
+        ),
+    )
+    .unwrap();
+    // The same versions as the crate itself, which are already downloaded.
+    let lock = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock");
+    if lock.exists() {
+        fs::copy(lock, crate_dir.join("Cargo.lock")).unwrap();
+    }
+
+    let mut cases = fs::read_dir(&cases_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect::<Vec<PathBuf>>();
+    cases.sort();
+    for case in &cases {
+        fs::copy(case, bin_dir.join(case.file_name().unwrap())).unwrap();
+    }
+
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()))
+        .args(["check", "--bins", "--keep-going", "--quiet"])
+        .args(["--message-format", "short"])
+        .current_dir(&crate_dir)
+        .env("CARGO_TARGET_DIR", crate_dir.join("target"))
+        .output()
+        .unwrap();
+    let errors = errors_by_case(&String::from_utf8_lossy(&output.stderr));
+
+    let overwrite = env::var("COMPILE_FAIL").is_ok_and(|value| value == "overwrite");
+    let mut failures = Vec::new();
+    for case in &cases {
+        let name = case.file_stem().unwrap().to_str().unwrap();
+        let found = errors.get(name).map(String::as_str).unwrap_or_default();
+        let stderr = case.with_extension("stderr");
+        if overwrite {
+            fs::write(&stderr, found).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&stderr)
+            .unwrap_or_default()
+            .replace("\r\n", "\n");
+        if found.is_empty() {
+            failures.push(format!("{}.rs compiled without errors", name));
+        } else if found != expected {
+            failures.push(format!(
+                "{}.rs reported other errors than expected\n\
+                 --- expected\n{}--- found\n{}",
+                name, expected, found,
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Collects the errors of each case from the short messages of cargo, along
+/// with the notes and help on the lines below them.
+fn errors_by_case(stderr: &str) -> BTreeMap<String, String> {
+    let mut errors = BTreeMap::<String, String>::new();
+    let mut current = None;
+    for line in stderr.lines() {
+        let line = line.trim_end();
+        if line.starts_with(' ') {
+            if let Some(case) = &current {
+                let error = errors.get_mut(case).unwrap();
+                error.push_str(line);
+                error.push('\n');
+            }
+            continue;
+        }
+
+        let line = line.replace('\\', "/");
+        current = line
+            .strip_prefix("src/bin/")
+            .and_then(|rest| rest.split_once(".rs:"))
+            .filter(|(_, message)| message.contains(": error"))
+            .map(|(case, _)| case.to_owned());
+        if let Some(case) = &current {
+            let error = errors.entry(case.clone()).or_default();
+            error.push_str(line.strip_prefix("src/bin/").unwrap());
+            error.push('\n');
+        }
+    }
+    errors
+}
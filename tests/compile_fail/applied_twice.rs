@@ -0,0 +1,7 @@
+#[enum_other::other(u8)]
+#[enum_other::other(u16)]
+enum Opcode {
+    Nop = 0,
+}
+
+fn main() {}
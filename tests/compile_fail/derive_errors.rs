@@ -0,0 +1,9 @@
+#[derive(enum_other::EnumOther)]
+#[other(type = u8, repr, non_exhaustive)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0,
+    Other(u8),
+}
+
+fn main() {}
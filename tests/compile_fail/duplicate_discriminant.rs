@@ -0,0 +1,8 @@
+#[enum_other::other(u8)]
+enum Opcode {
+    Query = 0,
+    Status = 2,
+    Notify = 2,
+}
+
+fn main() {}
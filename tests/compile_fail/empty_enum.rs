@@ -0,0 +1,4 @@
+#[enum_other::other(u8)]
+enum Opcode {}
+
+fn main() {}
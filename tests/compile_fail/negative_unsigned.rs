@@ -0,0 +1,7 @@
+#[enum_other::other(u8)]
+enum Temperature {
+    Freezing = -1,
+    Cold,
+}
+
+fn main() {}
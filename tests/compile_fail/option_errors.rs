@@ -0,0 +1,6 @@
+#[enum_other::other(u8, rename_all = "kebab-case", valid = 1..=9, display, display, bogus)]
+enum Opcode {
+    Nop = 0,
+}
+
+fn main() {}
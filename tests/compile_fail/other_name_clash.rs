@@ -0,0 +1,7 @@
+#[enum_other::other(u8)]
+enum Opcode {
+    Nop = 0,
+    Other = 1,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[enum_other::other(Opcod)]
+enum Opcode {
+    Nop = 0,
+}
+
+fn main() {}